@@ -2,26 +2,130 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{self, parse_macro_input, DeriveInput};
+use syn::{self, parse_macro_input, Data, DeriveInput, Type};
 
-#[proc_macro_derive(RegistryTicket)]
+/// Derives `RegistryTicket` for a newtype struct wrapping an unsigned integer
+///
+/// If the wrapped type is a `NonZero` integer, or the struct has the attribute
+/// `#[registry_ticket(nonzero)]`, the index is stored offset by one.
+///
+/// Also implements `TryFrom<usize>` for the ticket and `From<Ticket>` for `usize`,
+/// which refer to the `registry_ticket_manager` crate by its name.
+#[proc_macro_derive(RegistryTicket, attributes(registry_ticket))]
 pub fn registry_ticket_derive(input: TokenStream) -> TokenStream {
     let input_ast = parse_macro_input!(input as DeriveInput);
     let name = input_ast.ident;
 
+    let field_ty = match &input_ast.data {
+        Data::Struct(data) if data.fields.len() == 1 => {
+            data.fields.iter().next().unwrap().ty.clone()
+        }
+        _ => {
+            return syn::Error::new_spanned(
+                &name,
+                "RegistryTicket can only be derived for newtype structs",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+    let mut nonzero = is_nonzero(&field_ty);
+
+    for attr in &input_ast.attrs {
+        if !attr.path().is_ident("registry_ticket") {
+            continue;
+        }
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("nonzero") {
+                nonzero = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported registry_ticket attribute"))
+            }
+        });
+        if let Err(err) = result {
+            return err.to_compile_error().into();
+        }
+    }
+
     let generics = input_ast.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    let ticket_impl = if nonzero {
+        quote! {
+            impl #impl_generics RegistryTicket for #name #ty_generics #where_clause {
+                fn from_index(index: usize) -> Option<Self> {
+                    index
+                        .checked_add(1)?
+                        .try_into()
+                        .ok()
+                        .and_then(::core::num::NonZero::new)
+                        .map(Self)
+                }
+                unsafe fn from_index_unchecked(index: usize) -> Self {
+                    // SAFETY: the caller guarantees that `from_index` succeeds,
+                    // so the offset index fits into the type and is non-zero
+                    Self(unsafe { ::core::num::NonZero::new_unchecked((index + 1) as _) })
+                }
+                fn to_index(&self) -> usize {
+                    self.0.get() as usize - 1
+                }
+                fn max_index() -> Option<usize> {
+                    usize::try_from(<#field_ty>::MAX.get())
+                        .ok()
+                        .filter(|&max| max != usize::MAX)
+                        .map(|max| max - 1)
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics RegistryTicket for #name #ty_generics #where_clause {
+                fn from_index(index: usize) -> Option<Self> {
+                    index.try_into().ok().map(Self)
+                }
+                unsafe fn from_index_unchecked(index: usize) -> Self {
+                    Self(index as _)
+                }
+                fn to_index(&self) -> usize {
+                    self.0 as usize
+                }
+                fn max_index() -> Option<usize> {
+                    usize::try_from(<#field_ty>::MAX).ok().filter(|&max| max != usize::MAX)
+                }
+            }
+        }
+    };
+
     let expanded = quote! {
-        impl #impl_generics RegistryTicket for #name #ty_generics #where_clause {
-            fn from_index(index: usize) -> Option<Self> {
-                index.try_into().ok().map(Self)
+        #ticket_impl
+
+        impl #impl_generics ::core::convert::TryFrom<usize> for #name #ty_generics #where_clause {
+            type Error = ::registry_ticket_manager::error::IndexOutOfRange;
+            fn try_from(index: usize) -> Result<Self, Self::Error> {
+                <Self as RegistryTicket>::from_index(index)
+                    .ok_or(::registry_ticket_manager::error::IndexOutOfRange { index })
             }
-            fn to_index(&self) -> usize {
-                self.0 as usize
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for usize #where_clause {
+            fn from(ticket: #name #ty_generics) -> usize {
+                <#name #ty_generics as RegistryTicket>::to_index(&ticket)
             }
         }
     };
 
     expanded.into()
 }
+
+/// Returns whether the type's name looks like a `NonZero` integer, like `NonZeroU32` or `NonZero<u32>`
+fn is_nonzero(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|seg| seg.ident.to_string().starts_with("NonZero")),
+        _ => false,
+    }
+}
@@ -0,0 +1,247 @@
+use core::ops::{Index, IndexMut};
+
+use crate::*;
+
+/// The invariant behind unwrapping the slots below the length
+const FILLED_SLOTS: &str = "the slots below the length are always filled";
+
+/// A fixed-size registry manager storing at most `N` entries inline, without allocating
+///
+/// Identifiers are looked up with a linear scan, so this is only meant for small registries.
+/// The same ticket rules apply as with [`RegistryManager`].
+///
+/// # Examples
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::{array::ArrayRegistry, *};
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// # struct Ticket(u8);
+/// let mut man = ArrayRegistry::<i32, Ticket, &str, 2>::new();
+///
+/// let (cat, _) = man.insert("cat", 1).unwrap();
+/// let (dog, _) = man.insert("dog", 2).unwrap();
+/// assert!(man.insert("cow", 3).is_none());
+///
+/// assert_eq!(man.get_ticket_of(&"dog"), Some(dog));
+/// assert_eq!(man.get_ticket_full(cat), (&"cat", &1));
+/// assert_eq!(man[dog], 2);
+///
+/// // Existing identifiers can still be overwritten
+/// assert_eq!(man.insert("cat", 10), Some((cat, Some(1))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArrayRegistry<T, Ticket, Identifier, const N: usize>
+where
+    Ticket: RegistryTicket,
+    Identifier: Eq,
+{
+    entries: [Option<(Identifier, T)>; N],
+    len: usize,
+    _phantom: PhantomData<fn() -> Ticket>,
+}
+
+impl<T, Ticket, Identifier, const N: usize> ArrayRegistry<T, Ticket, Identifier, N>
+where
+    Ticket: RegistryTicket,
+    Identifier: Eq,
+{
+    /// Creates a new empty registry
+    pub fn new() -> Self {
+        Self {
+            entries: core::array::from_fn(|_| None),
+            len: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of stored values
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the registry is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether it is still possible to insert new values
+    ///
+    /// Fails when either all `N` slots are used, or the next index can't be converted into a ticket.
+    pub fn can_insert(&self) -> bool {
+        self.len < N && Ticket::from_index(self.len).is_some()
+    }
+
+    /// Returns the index of the given identifier with a linear scan
+    fn position(&self, id: &Identifier) -> Option<usize> {
+        self.iter_entries().position(|(entry_id, _)| entry_id == id)
+    }
+
+    /// Returns an iterator over the used slots
+    fn iter_entries(&self) -> impl Iterator<Item = (&Identifier, &T)> {
+        self.entries[..self.len].iter().map(|entry| {
+            let (id, val) = entry.as_ref().expect(FILLED_SLOTS);
+            (id, val)
+        })
+    }
+
+    /// Checks that the last index can be converted into a ticket, so that every index has one
+    ///
+    /// This always holds, since inserting checks it, but the iterator relies on it when producing tickets.
+    fn assert_tickets_fit(&self) {
+        assert!(
+            self.len == 0 || Ticket::from_index(self.len - 1).is_some(),
+            "the registry has more values than there are tickets"
+        );
+    }
+
+    /// Return if the given identifier exists in the registry
+    pub fn contains_id(&self, id: &Identifier) -> bool {
+        self.position(id).is_some()
+    }
+
+    /// Inserts the value to the registry with the given identifier
+    ///
+    /// Returns the ticket, and if the given identifier already had a value, returns that as well.
+    ///
+    /// Returns `None` if the registry is full, without modifying it.
+    pub fn insert(&mut self, id: Identifier, value: T) -> Option<(Ticket, Option<T>)> {
+        if let Some(idx) = self.position(&id) {
+            let ticket = Ticket::from_index(idx)?;
+            let (_, old) = self.entries[idx].as_mut().expect(FILLED_SLOTS);
+            return Some((ticket, Some(core::mem::replace(old, value))));
+        }
+
+        if self.len == N {
+            return None;
+        }
+        let ticket = Ticket::from_index(self.len)?;
+        self.entries[self.len] = Some((id, value));
+        self.len += 1;
+        Some((ticket, None))
+    }
+
+    /// Returns a reference to the value associated with the given id
+    pub fn get_id(&self, id: &Identifier) -> Option<&T> {
+        self.position(id).map(|idx| self.get_index(idx).1)
+    }
+
+    /// Returns a mutable reference to the value associated with the given id
+    pub fn get_id_mut(&mut self, id: &Identifier) -> Option<&mut T> {
+        let idx = self.position(id)?;
+        Some(self.get_index_mut(idx).1)
+    }
+
+    /// Returns the ticket of the given identifier, if it exists
+    pub fn get_ticket_of(&self, id: &Identifier) -> Option<Ticket> {
+        self.position(id).and_then(Ticket::from_index)
+    }
+
+    /// Returns the entry at the given index, panicking if it's out of bounds
+    fn get_index(&self, idx: usize) -> (&Identifier, &T) {
+        match self.entries[..self.len].get(idx) {
+            Some(Some((id, val))) => (id, val),
+            _ => index_out_of_bounds(idx, self.len),
+        }
+    }
+
+    /// Returns the entry at the given index, panicking if it's out of bounds
+    fn get_index_mut(&mut self, idx: usize) -> (&Identifier, &mut T) {
+        match self.entries[..self.len].get_mut(idx) {
+            Some(Some((id, val))) => (id, val),
+            _ => index_out_of_bounds(idx, self.len),
+        }
+    }
+
+    /// Returns a reference to the value associated with the given ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    pub fn get_ticket(&self, ticket: Ticket) -> &T {
+        self.get_index(ticket.to_index()).1
+    }
+
+    /// Returns references to the identifier and the value associated with the given ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    pub fn get_ticket_full(&self, ticket: Ticket) -> (&Identifier, &T) {
+        self.get_index(ticket.to_index())
+    }
+
+    /// Returns a mutable reference to the value associated with the given ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    pub fn get_ticket_mut(&mut self, ticket: Ticket) -> &mut T {
+        self.get_index_mut(ticket.to_index()).1
+    }
+
+    /// Returns a reference to the value associated with the given ticket
+    ///
+    /// Returns `None` if the ticket is out of bounds.
+    pub fn try_get_ticket(&self, ticket: Ticket) -> Option<&T> {
+        let idx = ticket.to_index();
+        (idx < self.len).then(|| self.get_index(idx).1)
+    }
+
+    /// Returns a referencing iterator over the values
+    ///
+    /// The iterator's item-type is `(Ticket, &'a Identifier, &'a T)`.
+    ///
+    /// # Panics
+    ///
+    /// Checks once that every index has a ticket, like [`RegistryManager::iter`].
+    /// Inserting already makes sure of that, even when the ticket type is too narrow for `N`,
+    /// so this only panics if the [`RegistryTicket`] implementation breaks its rules.
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (Ticket, &Identifier, &T)> + ExactSizeIterator {
+        self.assert_tickets_fit();
+        self.entries[..self.len]
+            .iter()
+            .enumerate()
+            .map(|(idx, entry)| {
+                let (id, val) = entry.as_ref().expect(FILLED_SLOTS);
+                let ticket =
+                    Ticket::from_index(idx).expect("iterator indices were checked on creation");
+                (ticket, id, val)
+            })
+    }
+}
+
+impl<T, Ticket, Identifier, const N: usize> Default for ArrayRegistry<T, Ticket, Identifier, N>
+where
+    Ticket: RegistryTicket,
+    Identifier: Eq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, Ticket, Identifier, const N: usize> Index<Ticket>
+    for ArrayRegistry<T, Ticket, Identifier, N>
+where
+    Ticket: RegistryTicket,
+    Identifier: Eq,
+{
+    type Output = T;
+    /// Returns a reference to the value associated by the ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    fn index(&self, ticket: Ticket) -> &Self::Output {
+        self.get_ticket(ticket)
+    }
+}
+
+impl<T, Ticket, Identifier, const N: usize> IndexMut<Ticket>
+    for ArrayRegistry<T, Ticket, Identifier, N>
+where
+    Ticket: RegistryTicket,
+    Identifier: Eq,
+{
+    /// Returns a mutable reference to the value associated by the ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    fn index_mut(&mut self, ticket: Ticket) -> &mut Self::Output {
+        self.get_ticket_mut(ticket)
+    }
+}
@@ -0,0 +1,157 @@
+//! A compact, length-prefixed binary format, available with the `binary` feature
+//!
+//! The format is a little-endian `u64` count of entries,
+//! followed by each entry's identifier and value encoded with [`ByteCodec`], in ticket order.
+
+use std::io::{self, Read, Write};
+
+use crate::*;
+
+/// A type which can be encoded to and decoded from bytes
+///
+/// Integers and floats are encoded in little-endian byte order, `usize` and `isize` as 64 bits,
+/// and strings as a `u64` byte length followed by the UTF-8 bytes.
+pub trait ByteCodec: Sized {
+    /// Writes the encoded value into the writer
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()>;
+
+    /// Reads and decodes a value from the reader
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self>;
+}
+
+macro_rules! impl_byte_codec_num {
+    ($($ty:ty),*) => {
+        $(
+            impl ByteCodec for $ty {
+                fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+                    w.write_all(&self.to_le_bytes())
+                }
+
+                fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+                    let mut bytes = [0; core::mem::size_of::<$ty>()];
+                    r.read_exact(&mut bytes)?;
+                    Ok(Self::from_le_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_byte_codec_num!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl ByteCodec for usize {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as u64).encode(w)
+    }
+
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        u64::decode(r)?.try_into().map_err(invalid_data)
+    }
+}
+
+impl ByteCodec for isize {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as i64).encode(w)
+    }
+
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        i64::decode(r)?.try_into().map_err(invalid_data)
+    }
+}
+
+impl ByteCodec for bool {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        (*self as u8).encode(w)
+    }
+
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        match u8::decode(r)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid_data("invalid boolean")),
+        }
+    }
+}
+
+impl ByteCodec for String {
+    fn encode<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.len().encode(w)?;
+        w.write_all(self.as_bytes())
+    }
+
+    fn decode<R: Read>(r: &mut R) -> io::Result<Self> {
+        let len = usize::decode(r)?;
+        let mut bytes = Vec::new();
+        r.take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        String::from_utf8(bytes).map_err(invalid_data)
+    }
+}
+
+fn invalid_data<E>(err: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+impl<T, Ticket, Identifier, S> RegistryManager<T, Ticket, Identifier, S>
+where
+    T: ByteCodec,
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq + ByteCodec,
+    S: BuildHasher,
+{
+    /// Writes the registry into the writer in the [binary format](crate::binary)
+    pub fn write_to<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.len().encode(w)?;
+        for (id, val) in &self.map {
+            id.encode(w)?;
+            val.encode(w)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a registry written by [`write_to`](Self::write_to) from the reader
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the data contains duplicate identifiers,
+    /// or more entries than there are tickets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<u32, Ticket>::new();
+    /// man.insert("cat".to_string(), 1).unwrap();
+    /// man.insert("dog".to_string(), 2).unwrap();
+    ///
+    /// let mut bytes = Vec::new();
+    /// man.write_to(&mut bytes).unwrap();
+    ///
+    /// let read = RegistryManager::<u32, Ticket>::read_from(&mut bytes.as_slice()).unwrap();
+    /// assert_eq!(read.iter().collect::<Vec<_>>(), man.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn read_from<R: Read>(r: &mut R) -> io::Result<Self>
+    where
+        S: Default,
+    {
+        let len = usize::decode(r)?;
+        let mut man = Self::with_hasher(S::default());
+
+        for _ in 0..len {
+            let id = Identifier::decode(r)?;
+            let val = T::decode(r)?;
+            match man.insert(id, val) {
+                Some((_, None)) => {}
+                Some((_, Some(_))) => return Err(invalid_data("duplicate identifier")),
+                None => return Err(invalid_data(RegistryFull)),
+            }
+        }
+        Ok(man)
+    }
+}
@@ -0,0 +1,170 @@
+use core::{
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+use crate::*;
+
+/// An invariant lifetime, which makes different brands incompatible with each other
+type Brand<'brand> = PhantomData<fn(&'brand ()) -> &'brand ()>;
+
+/// A ticket which can only be used with the [`BrandedRegistry`] that created it
+///
+/// Using the ticket with any other branded registry is a compile error:
+///
+/// ```compile_fail
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::*;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// # struct Ticket(u16);
+/// RegistryManager::<i32, Ticket, &str>::new().with_branded(|mut a| {
+///     let (cat, _) = a.insert("cat", 1).unwrap();
+///
+///     RegistryManager::<i32, Ticket, &str>::new().with_branded(|b| {
+///         b.get_ticket(cat);
+///     });
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BrandedTicket<'brand, Ticket> {
+    ticket: Ticket,
+    _brand: Brand<'brand>,
+}
+
+impl<Ticket> BrandedTicket<'_, Ticket> {
+    /// Returns the plain ticket, which can be used with the registry's [`RegistryManager`]
+    pub fn ticket(self) -> Ticket {
+        self.ticket
+    }
+}
+
+/// A [`RegistryManager`] whose tickets are branded with a unique lifetime
+///
+/// Created by [`RegistryManager::with_branded`].
+/// Tickets of one branded registry can't be used with another one, even if their ticket types are the same.
+#[derive(Debug)]
+pub struct BrandedRegistry<'brand, T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    pub(crate) inner: RegistryManager<T, Ticket, Identifier, S>,
+    pub(crate) _brand: Brand<'brand>,
+}
+
+impl<'brand, T, Ticket, Identifier, S> BrandedRegistry<'brand, T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    fn brand(ticket: Ticket) -> BrandedTicket<'brand, Ticket> {
+        BrandedTicket {
+            ticket,
+            _brand: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the unbranded registry manager
+    pub fn as_inner(&self) -> &RegistryManager<T, Ticket, Identifier, S> {
+        &self.inner
+    }
+
+    /// Returns the unbranded registry manager
+    pub fn into_inner(self) -> RegistryManager<T, Ticket, Identifier, S> {
+        self.inner
+    }
+
+    /// Returns the number of stored values
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if the registry is empty
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Inserts the value to the registry with the given identifier
+    ///
+    /// See [`RegistryManager::insert`].
+    pub fn insert(
+        &mut self,
+        id: Identifier,
+        value: T,
+    ) -> Option<(BrandedTicket<'brand, Ticket>, Option<T>)> {
+        let (ticket, old) = self.inner.insert(id, value)?;
+        Some((Self::brand(ticket), old))
+    }
+
+    /// Returns a reference to the value associated with the given id
+    pub fn get_id(&self, id: &Identifier) -> Option<&T> {
+        self.inner.get_id(id)
+    }
+
+    /// Returns the ticket of the given identifier, if it exists
+    pub fn get_ticket_of(&self, id: &Identifier) -> Option<BrandedTicket<'brand, Ticket>> {
+        self.inner.get_ticket_of(id).map(Self::brand)
+    }
+
+    /// Returns a reference to the value associated with the given ticket
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let man = RegistryManager::<i32, Ticket, &str>::new().with_branded(|mut reg| {
+    ///     let (cat, _) = reg.insert("cat", 1).unwrap();
+    ///     *reg.get_ticket_mut(cat) += 1;
+    ///     assert_eq!(*reg.get_ticket(cat), 2);
+    ///     assert_eq!(reg.get_ticket_of(&"cat"), Some(cat));
+    ///     reg.into_inner()
+    /// });
+    ///
+    /// assert_eq!(man.get_id(&"cat"), Some(&2));
+    /// ```
+    pub fn get_ticket(&self, ticket: BrandedTicket<'brand, Ticket>) -> &T {
+        self.inner.get_ticket(ticket.ticket)
+    }
+
+    /// Returns references to the identifier and the value associated with the given ticket
+    pub fn get_ticket_full(&self, ticket: BrandedTicket<'brand, Ticket>) -> (&Identifier, &T) {
+        self.inner.get_ticket_full(ticket.ticket)
+    }
+
+    /// Returns a mutable reference to the value associated with the given ticket
+    pub fn get_ticket_mut(&mut self, ticket: BrandedTicket<'brand, Ticket>) -> &mut T {
+        self.inner.get_ticket_mut(ticket.ticket)
+    }
+}
+
+impl<'brand, T, Ticket, Identifier, S> Index<BrandedTicket<'brand, Ticket>>
+    for BrandedRegistry<'brand, T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    type Output = T;
+    /// Returns a reference to the value associated by the ticket
+    fn index(&self, ticket: BrandedTicket<'brand, Ticket>) -> &Self::Output {
+        self.get_ticket(ticket)
+    }
+}
+
+impl<'brand, T, Ticket, Identifier, S> IndexMut<BrandedTicket<'brand, Ticket>>
+    for BrandedRegistry<'brand, T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Returns a mutable reference to the value associated by the ticket
+    fn index_mut(&mut self, ticket: BrandedTicket<'brand, Ticket>) -> &mut Self::Output {
+        self.get_ticket_mut(ticket)
+    }
+}
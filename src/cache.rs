@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+use core::{
+    hash::{BuildHasher, Hash},
+    ops::Deref,
+};
+
+use crate::*;
+
+/// A [`RegistryManager`] which remembers the tickets of the most recently resolved identifiers
+///
+/// Created by [`RegistryManager::with_ticket_cache`].
+/// The cache is a small least recently used list, which is scanned linearly by comparing the identifiers.
+/// This only pays off when a few identifiers are resolved over and over again,
+/// and their comparison is cheaper than hashing and looking them up.
+/// For evenly spread lookups the cache only adds overhead.
+///
+/// It dereferences to the registry manager for reading, but only allows inserting as a mutation,
+/// since that never changes the tickets of existing identifiers.
+///
+/// # Examples
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::*;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// # struct Ticket(u16);
+/// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+/// man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+///
+/// let mut cached = man.clone().with_ticket_cache(2);
+/// for _ in 0..3 {
+///     for id in ["cat", "dog", "cow", "pig"] {
+///         assert_eq!(cached.memoize_ticket(&id), man.get_ticket_of(&id));
+///     }
+/// }
+///
+/// let (pig, _) = cached.insert("pig", 4).unwrap();
+/// assert_eq!(cached.memoize_ticket(&"pig"), Some(pig));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CachingRegistry<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    pub(crate) inner: RegistryManager<T, Ticket, Identifier, S>,
+    /// The cached entries, the most recently used first
+    pub(crate) cache: Vec<(Identifier, Ticket)>,
+    pub(crate) cache_size: usize,
+}
+
+impl<T, Ticket, Identifier, S> CachingRegistry<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket + Clone,
+    Identifier: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    /// Returns the ticket of the given identifier, if it exists, remembering it for later calls
+    pub fn memoize_ticket(&mut self, id: &Identifier) -> Option<Ticket> {
+        if let Some(pos) = self.cache.iter().position(|(cached, _)| cached == id) {
+            self.cache[..=pos].rotate_right(1);
+            return Some(self.cache[0].1.clone());
+        }
+
+        let ticket = self.inner.get_ticket_of(id)?;
+        if self.cache_size > 0 {
+            self.cache.truncate(self.cache_size - 1);
+            self.cache.insert(0, (id.clone(), ticket.clone()));
+        }
+        Some(ticket)
+    }
+
+    /// Inserts the value to the registry with the given identifier
+    ///
+    /// See [`RegistryManager::insert`].
+    pub fn insert(&mut self, id: Identifier, value: T) -> Option<(Ticket, Option<T>)> {
+        self.inner.insert(id, value)
+    }
+
+    /// Forgets all of the cached tickets
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+
+    /// Returns the registry manager, dropping the cache
+    pub fn into_inner(self) -> RegistryManager<T, Ticket, Identifier, S> {
+        self.inner
+    }
+}
+
+impl<T, Ticket, Identifier, S> Deref for CachingRegistry<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    type Target = RegistryManager<T, Ticket, Identifier, S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
@@ -0,0 +1,86 @@
+use core::hash::{BuildHasher, Hash};
+
+use crate::*;
+
+/// A position in a [`RegistryManager`], which can be moved back and forth in ticket order
+///
+/// Created by [`RegistryManager::cursor_at`]. It always points to an existing entry,
+/// and moving it past either end returns `None`.
+///
+/// # Examples
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::*;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// # struct Ticket(u16);
+/// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+/// let tickets = man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+///
+/// let cursor = man.cursor_at(tickets[0]).unwrap();
+/// assert_eq!(cursor.current(), (&"cat", &1));
+/// assert!(cursor.prev().is_none());
+///
+/// let cursor = cursor.next().unwrap().next().unwrap();
+/// assert_eq!(cursor.ticket(), tickets[2]);
+/// assert_eq!(cursor.current(), (&"cow", &3));
+/// assert!(cursor.next().is_none());
+///
+/// let cursor = cursor.prev().unwrap();
+/// assert_eq!(cursor.current(), (&"dog", &2));
+/// ```
+#[derive(Debug)]
+pub struct Cursor<'a, T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    pub(crate) registry: &'a RegistryManager<T, Ticket, Identifier, S>,
+    pub(crate) index: usize,
+}
+
+impl<T, Ticket, Identifier, S> Clone for Cursor<'_, T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, Ticket, Identifier, S> Copy for Cursor<'_, T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+}
+
+impl<'a, T, Ticket, Identifier, S> Cursor<'a, T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Returns the ticket of the entry the cursor points to
+    pub fn ticket(&self) -> Ticket {
+        Ticket::from_index(self.index).expect("every index of the registry has a ticket")
+    }
+
+    /// Returns references to the identifier and the value the cursor points to
+    pub fn current(&self) -> (&'a Identifier, &'a T) {
+        self.registry.expect_index(self.index)
+    }
+
+    /// Returns a cursor to the next entry, or `None` if this is the last one
+    pub fn next(self) -> Option<Self> {
+        let index = self.index + 1;
+        (index < self.registry.len()).then_some(Self { index, ..self })
+    }
+
+    /// Returns a cursor to the previous entry, or `None` if this is the first one
+    pub fn prev(self) -> Option<Self> {
+        let index = self.index.checked_sub(1)?;
+        Some(Self { index, ..self })
+    }
+}
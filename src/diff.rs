@@ -0,0 +1,55 @@
+use core::hash::{BuildHasher, Hash};
+
+use crate::*;
+
+/// The differences between two [`RegistryManager`]s sharing an identifier space
+///
+/// Created by [`RegistryManager::diff`]. Each category is computed lazily when iterated,
+/// without cloning or allocating.
+#[derive(Debug)]
+pub struct RegistryDiff<'a, T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    pub(crate) left: &'a RegistryManager<T, Ticket, Identifier, S>,
+    pub(crate) right: &'a RegistryManager<T, Ticket, Identifier, S>,
+}
+
+impl<'a, T, Ticket, Identifier, S> RegistryDiff<'a, T, Ticket, Identifier, S>
+where
+    T: PartialEq,
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Returns an iterator over the entries whose identifier only exists in the first registry
+    pub fn only_in_self(&self) -> impl Iterator<Item = (Ticket, &'a Identifier, &'a T)> {
+        let right = self.right;
+        self.left
+            .iter()
+            .filter(move |(_, id, _)| !right.contains_id(id))
+    }
+
+    /// Returns an iterator over the entries whose identifier only exists in the second registry
+    pub fn only_in_other(&self) -> impl Iterator<Item = (Ticket, &'a Identifier, &'a T)> {
+        let left = self.left;
+        self.right
+            .iter()
+            .filter(move |(_, id, _)| !left.contains_id(id))
+    }
+
+    /// Returns an iterator over the identifiers that exist in both registries with differing values
+    ///
+    /// The item-type is `(&'a Identifier, (Ticket, &'a T), (Ticket, &'a T))`,
+    /// with the ticket and value of the first registry, followed by those of the second one.
+    pub fn changed(
+        &self,
+    ) -> impl Iterator<Item = (&'a Identifier, (Ticket, &'a T), (Ticket, &'a T))> {
+        let right = self.right;
+        self.left.iter().filter_map(move |(ticket, id, val)| {
+            let (other_ticket, _, other_val) = right.get_id_full(id)?;
+            (val != other_val).then_some((id, (ticket, val), (other_ticket, other_val)))
+        })
+    }
+}
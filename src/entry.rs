@@ -0,0 +1,107 @@
+use indexmap::map::Entry;
+
+/// A handle to an existing entry of a [`RegistryManager`](crate::RegistryManager), found by its ticket
+///
+/// Created by [`RegistryManager::ticket_entry`](crate::RegistryManager::ticket_entry).
+/// It allows reading the entry and then deciding whether to mutate it, without a second lookup.
+#[derive(Debug)]
+pub struct TicketEntry<'a, T, Identifier> {
+    pub(crate) id: &'a Identifier,
+    pub(crate) value: &'a mut T,
+}
+
+impl<'a, T, Identifier> TicketEntry<'a, T, Identifier> {
+    /// Returns a reference to the entry's identifier
+    pub fn id(&self) -> &Identifier {
+        self.id
+    }
+
+    /// Returns a reference to the entry's value
+    pub fn get(&self) -> &T {
+        self.value
+    }
+
+    /// Returns a mutable reference to the entry's value
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value
+    }
+
+    /// Converts the entry into a mutable reference to its value, with the lifetime of the registry borrow
+    pub fn into_mut(self) -> &'a mut T {
+        self.value
+    }
+}
+
+/// A read-only handle to an entry of a [`RegistryManager`](crate::RegistryManager), bundling its ticket
+///
+/// Created by [`RegistryManager::ticket_ref`](crate::RegistryManager::ticket_ref).
+#[derive(Debug, Clone, Copy)]
+pub struct TicketRef<'a, T, Ticket, Identifier> {
+    pub(crate) ticket: Ticket,
+    pub(crate) id: &'a Identifier,
+    pub(crate) value: &'a T,
+}
+
+impl<'a, T, Ticket: Clone, Identifier> TicketRef<'a, T, Ticket, Identifier> {
+    /// Returns the entry's ticket
+    pub fn ticket(&self) -> Ticket {
+        self.ticket.clone()
+    }
+
+    /// Returns a reference to the entry's identifier, with the lifetime of the registry borrow
+    pub fn id(&self) -> &'a Identifier {
+        self.id
+    }
+
+    /// Returns a reference to the entry's value, with the lifetime of the registry borrow
+    pub fn value(&self) -> &'a T {
+        self.value
+    }
+}
+
+/// A ticket-aware handle to an entry of a [`RegistryManager`](crate::RegistryManager), which may be vacant
+///
+/// Created by [`RegistryManager::id_entry`](crate::RegistryManager::id_entry).
+/// The ticket is resolved when the handle is created,
+/// so inserting into a vacant entry fails if there are no tickets left for it.
+#[derive(Debug)]
+pub struct TicketEntryApi<'a, T, Ticket, Identifier> {
+    pub(crate) entry: Entry<'a, Identifier, T>,
+    pub(crate) ticket: Option<Ticket>,
+}
+
+impl<'a, T, Ticket: Clone, Identifier> TicketEntryApi<'a, T, Ticket, Identifier> {
+    /// Returns the entry's ticket, or the ticket a vacant entry would get when inserted
+    ///
+    /// Returns `None` if a vacant entry can't be inserted.
+    pub fn ticket(&self) -> Option<Ticket> {
+        self.ticket.clone()
+    }
+
+    /// Returns a reference to the entry's identifier
+    pub fn id(&self) -> &Identifier {
+        self.entry.key()
+    }
+
+    /// Returns whether the entry already has a value
+    pub fn is_occupied(&self) -> bool {
+        matches!(self.entry, Entry::Occupied(_))
+    }
+
+    /// Inserts the given value if the entry is vacant,
+    /// and returns the ticket with a mutable reference to the entry's value
+    ///
+    /// Returns `None` without inserting if a vacant entry can't get a ticket.
+    pub fn or_insert(self, value: T) -> Option<(Ticket, &'a mut T)> {
+        self.or_insert_with(|| value)
+    }
+
+    /// Inserts the value returned by `make` if the entry is vacant,
+    /// and returns the ticket with a mutable reference to the entry's value
+    ///
+    /// Returns `None` without calling `make` if a vacant entry can't get a ticket.
+    pub fn or_insert_with(self, make: impl FnOnce() -> T) -> Option<(Ticket, &'a mut T)> {
+        let ticket = self.ticket?;
+        Some((ticket, self.entry.or_insert_with(make)))
+    }
+}
@@ -0,0 +1,138 @@
+use core::{error::Error, fmt};
+
+/// The error returned when a [`RegistryManager`](crate::RegistryManager) can't fit any more values
+///
+/// This happens when the index of a new value can't be converted into a ticket
+/// by [`RegistryTicket::from_index`](crate::RegistryTicket::from_index).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegistryFull;
+
+impl fmt::Display for RegistryFull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the registry has run out of tickets")
+    }
+}
+
+impl Error for RegistryFull {}
+
+/// The error returned when an index can't be converted into a ticket
+///
+/// Returned by the `TryFrom<usize>` implementations of derived tickets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IndexOutOfRange {
+    /// The index which couldn't be converted
+    pub index: usize,
+}
+
+impl fmt::Display for IndexOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "index {} is out of the ticket type's range", self.index)
+    }
+}
+
+impl Error for IndexOutOfRange {}
+
+/// The error returned by [`RegistryManager::get_or_try_insert_with`](crate::RegistryManager::get_or_try_insert_with)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GetOrInsertError<E> {
+    /// The registry has run out of tickets, so a new value couldn't be inserted
+    Full,
+    /// Constructing the new value failed
+    Constructor(E),
+}
+
+impl<E: fmt::Display> fmt::Display for GetOrInsertError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full => RegistryFull.fmt(f),
+            Self::Constructor(err) => write!(f, "failed to construct the value: {err}"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for GetOrInsertError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Full => None,
+            Self::Constructor(err) => Some(err),
+        }
+    }
+}
+
+/// The error returned by [`RegistryManager::insert_if_absent`](crate::RegistryManager::insert_if_absent)
+///
+/// Both variants hand the rejected value back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InsertError<Ticket, T> {
+    /// The identifier already exists, with the given ticket
+    Exists(Ticket, T),
+    /// The registry has run out of tickets
+    Full(T),
+}
+
+impl<Ticket, T> InsertError<Ticket, T> {
+    /// Returns the value which wasn't inserted
+    pub fn into_value(self) -> T {
+        match self {
+            Self::Exists(_, value) | Self::Full(value) => value,
+        }
+    }
+}
+
+impl<Ticket, T> fmt::Display for InsertError<Ticket, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exists(..) => write!(f, "the identifier already exists in the registry"),
+            Self::Full(_) => RegistryFull.fmt(f),
+        }
+    }
+}
+
+impl<Ticket: fmt::Debug, T: fmt::Debug> Error for InsertError<Ticket, T> {}
+
+/// The error returned when a ticket doesn't point to any value in a [`RegistryManager`](crate::RegistryManager)
+///
+/// Usually this means the ticket came from a different registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidTicket {
+    /// The index of the ticket
+    pub index: usize,
+    /// The length of the registry the ticket was used with
+    pub len: usize,
+}
+
+impl fmt::Display for InvalidTicket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ticket index {} out of bounds (len {})",
+            self.index, self.len
+        )
+    }
+}
+
+impl Error for InvalidTicket {}
+
+/// The error returned by [`RegistryManager::rename`](crate::RegistryManager::rename)
+///
+/// Both variants hand the new identifier back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RenameError<Identifier> {
+    /// The identifier to rename doesn't exist
+    NotFound(Identifier),
+    /// The new identifier is already in use
+    AlreadyExists(Identifier),
+}
+
+impl<Identifier> fmt::Display for RenameError<Identifier> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(_) => write!(f, "the identifier to rename doesn't exist"),
+            Self::AlreadyExists(_) => {
+                write!(f, "the new identifier already exists in the registry")
+            }
+        }
+    }
+}
+
+impl<Identifier: fmt::Debug> Error for RenameError<Identifier> {}
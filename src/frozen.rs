@@ -0,0 +1,85 @@
+use alloc::sync::Arc;
+use core::{
+    hash::{BuildHasher, Hash},
+    ops::{Deref, Index},
+};
+
+use crate::*;
+
+/// An immutable, shared view of a [`RegistryManager`] which can't grow anymore
+///
+/// Created by [`RegistryManager::freeze`]. It dereferences to the registry manager,
+/// so all of its reading methods and iterators are available, but nothing can be inserted.
+///
+/// Cloning only increments a reference count, and the view can be shared across threads
+/// if the identifiers, values, and the hasher allow it.
+///
+/// # Examples
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::*;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// # struct Ticket(u16);
+/// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+/// let (cat, _) = man.insert("cat", 1).unwrap();
+/// man.insert("dog", 2).unwrap();
+///
+/// let frozen = man.freeze();
+/// let handles: Vec<_> = [frozen.clone(), frozen.clone()]
+///     .into_iter()
+///     .map(|frozen| std::thread::spawn(move || (frozen[cat], frozen.get_ticket_of(&"dog"))))
+///     .collect();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), (1, frozen.get_ticket_of(&"dog")));
+/// }
+/// assert_eq!(frozen.iter().count(), 2);
+/// ```
+#[derive(Debug)]
+pub struct FrozenRegistry<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    pub(crate) inner: Arc<RegistryManager<T, Ticket, Identifier, S>>,
+}
+
+impl<T, Ticket, Identifier, S> Clone for FrozenRegistry<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T, Ticket, Identifier, S> Deref for FrozenRegistry<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    type Target = RegistryManager<T, Ticket, Identifier, S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T, Ticket, Identifier, S> Index<Ticket> for FrozenRegistry<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    type Output = T;
+    /// Returns a reference to the value associated by the ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    fn index(&self, ticket: Ticket) -> &Self::Output {
+        self.inner.get_ticket(ticket)
+    }
+}
@@ -1,27 +1,96 @@
-use std::{iter::FusedIterator, marker::PhantomData};
+use core::{iter::FusedIterator, marker::PhantomData};
 
 use crate::*;
 
 /// A helper function to map the internal iterator's output values into correct ones
+///
+/// Should only be used by iterators which were created after checking that every index has a ticket.
+///
+/// The conversion is still checked, since a safe [`RegistryTicket`] implementation
+/// can't be trusted to succeed for every index below the checked one.
 fn map_next<Ticket, Id, T>(input: (usize, (Id, T))) -> (Ticket, Id, T)
 where
     Ticket: RegistryTicket,
 {
     let (idx, (id, val)) = input;
-    (Ticket::from_index(idx).unwrap(), id, val)
+    let ticket = Ticket::from_index(idx).expect("iterator indices were checked on creation");
+    (ticket, id, val)
 }
 
 /// A referencing iterator over the values of a [`RegistryManager`]
 ///
 /// The iterator item-type is `(Ticket, &'a Identifier, &'a T)`
+///
+/// Creating the iterator checks once that every index of the registry can be converted into a ticket,
+/// so producing the tickets of the items never fails, even when the registry is completely full:
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::*;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// # struct Ticket(u8);
+/// let mut man = RegistryManager::<i32, Ticket, i32>::new();
+/// man.insert_many((0..256).map(|i| (i, i))).unwrap();
+/// assert!(!man.can_insert());
+///
+/// assert_eq!(man.iter().count(), 256);
+/// for (ticket, id, _) in man.iter().rev() {
+///     assert_eq!(ticket.to_index(), *id as usize);
+/// }
+/// ```
+///
+/// The tickets stay correct when iterating from both ends:
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::*;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// # struct Ticket(u16);
+/// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+/// man.insert_many([("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5), ("f", 6)]).unwrap();
+///
+/// let mut iter = man.iter();
+/// let yielded = [
+///     iter.next(),
+///     iter.next_back(),
+///     iter.nth(1),
+///     iter.next_back(),
+///     iter.next(),
+///     iter.next_back(),
+/// ];
+///
+/// let ids: Vec<_> = yielded.iter().flatten().map(|(_, id, _)| **id).collect();
+/// assert_eq!(ids, ["a", "f", "c", "e", "d"]);
+/// for (ticket, id, _) in yielded.into_iter().flatten() {
+///     assert_eq!(man.get_ticket_of(id), Some(ticket));
+/// }
+/// ```
+///
+/// The length only counts the remaining items:
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::*;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// # struct Ticket(u16);
+/// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+/// man.insert_many([("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5)]).unwrap();
+///
+/// let mut iter = man.iter();
+/// iter.next();
+/// iter.next();
+/// assert_eq!(iter.len(), 3);
+/// assert_eq!(iter.size_hint(), (3, Some(3)));
+/// assert_eq!(iter.count(), 3);
+/// ```
 #[derive(Debug, Clone)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct Iter<'a, T, Ticket, Identifier>
 where
     Ticket: RegistryTicket,
 {
-    pub(crate) iter: std::iter::Enumerate<indexmap::map::Iter<'a, Identifier, T>>,
-    pub(crate) _phantom: PhantomData<*const Ticket>,
+    pub(crate) iter: core::iter::Enumerate<indexmap::map::Iter<'a, Identifier, T>>,
+    pub(crate) _phantom: PhantomData<fn() -> Ticket>,
 }
 
 impl<'a, T, Ticket, Identifier> Iterator for Iter<'a, T, Ticket, Identifier>
@@ -88,14 +157,34 @@ impl<T, Ticket, Identifier> FusedIterator for Iter<'_, T, Ticket, Identifier> wh
 /// A mutable iterator over the values of a [`RegistryManager`]
 ///
 /// The iterator item-type is `(Ticket, &'a Identifier, &'a mut T)`
+///
+/// Like with [`Iter`], every index is checked to fit into a ticket when the iterator is created.
+///
+/// The length only counts the remaining items:
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::*;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// # struct Ticket(u16);
+/// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+/// man.insert_many([("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5)]).unwrap();
+///
+/// let mut iter = man.iter_mut();
+/// iter.next();
+/// iter.next();
+/// assert_eq!(iter.len(), 3);
+/// assert_eq!(iter.size_hint(), (3, Some(3)));
+/// assert_eq!(iter.count(), 3);
+/// ```
 #[derive(Debug)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct IterMut<'a, T, Ticket, Identifier>
 where
     Ticket: RegistryTicket,
 {
-    pub(crate) iter: std::iter::Enumerate<indexmap::map::IterMut<'a, Identifier, T>>,
-    pub(crate) _phantom: PhantomData<*const Ticket>,
+    pub(crate) iter: core::iter::Enumerate<indexmap::map::IterMut<'a, Identifier, T>>,
+    pub(crate) _phantom: PhantomData<fn() -> Ticket>,
 }
 
 impl<'a, T, Ticket, Identifier> Iterator for IterMut<'a, T, Ticket, Identifier>
@@ -158,3 +247,98 @@ impl<T, Ticket, Identifier> FusedIterator for IterMut<'_, T, Ticket, Identifier>
     Ticket: RegistryTicket
 {
 }
+
+/// An owning iterator over the values of a [`RegistryManager`]
+///
+/// The iterator item-type is `(Ticket, Identifier, T)`
+///
+/// Like with [`Iter`], every index is checked to fit into a ticket when the iterator is created,
+/// and the tickets stay correct when iterating from both ends:
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::*;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// # struct Ticket(u16);
+/// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+/// let tickets = man.insert_many([("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5)]).unwrap();
+///
+/// let mut iter = man.into_iter();
+/// assert_eq!(iter.next(), Some((tickets[0], "a", 1)));
+/// assert_eq!(iter.next_back(), Some((tickets[4], "e", 5)));
+/// assert_eq!(iter.len(), 3);
+/// assert_eq!(iter.nth_back(1), Some((tickets[2], "c", 3)));
+/// assert_eq!(iter.next(), Some((tickets[1], "b", 2)));
+/// assert_eq!(iter.next_back(), None);
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoIter<T, Ticket, Identifier>
+where
+    Ticket: RegistryTicket,
+{
+    pub(crate) iter: core::iter::Enumerate<indexmap::map::IntoIter<Identifier, T>>,
+    pub(crate) _phantom: PhantomData<fn() -> Ticket>,
+}
+
+impl<T, Ticket, Identifier> Iterator for IntoIter<T, Ticket, Identifier>
+where
+    Ticket: RegistryTicket,
+{
+    type Item = (Ticket, Identifier, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(map_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.iter.len()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(map_next)
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(map_next)
+    }
+
+    fn collect<C>(self) -> C
+    where
+        C: FromIterator<Self::Item>,
+    {
+        self.iter.map(map_next).collect()
+    }
+}
+
+impl<T, Ticket, Identifier> DoubleEndedIterator for IntoIter<T, Ticket, Identifier>
+where
+    Ticket: RegistryTicket,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(map_next)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n).map(map_next)
+    }
+}
+
+impl<T, Ticket, Identifier> ExactSizeIterator for IntoIter<T, Ticket, Identifier>
+where
+    Ticket: RegistryTicket,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T, Ticket, Identifier> FusedIterator for IntoIter<T, Ticket, Identifier> where
+    Ticket: RegistryTicket
+{
+}
@@ -5,6 +5,10 @@
 //! an extra layer of safety by making it more difficult to mix the indices of different collections.
 //!
 //! Items in the collection can't be removed, which means that old tickets will never be invalidated.
+//! The only exceptions are [`keep_first`](RegistryManager::keep_first), which drops the newest values,
+//! and methods which explicitly reorder the values, such as
+//! [`sort_unstable_by`](RegistryManager::sort_unstable_by) and [`swap_tickets`](RegistryManager::swap_tickets),
+//! which document how the old tickets change.
 //!
 //! # Examples
 //!
@@ -35,9 +39,9 @@
 //! fn main() {
 //!     let mut man = AnimalRegistry::new();
 //!
-//!     let (cat, _old) = man.insert("cat", Animal::new("feline", "meow")).unwrap();
-//!     let (dog, _old) = man.insert("dog", Animal::new("canine", "woof")).unwrap();
-//!     let (cow, _old) = man.insert("cow", Animal::new("bovine", "moo")).unwrap();
+//!     let (cat, _old) = man.insert("cat".to_string(), Animal::new("feline", "meow")).unwrap();
+//!     let (dog, _old) = man.insert("dog".to_string(), Animal::new("canine", "woof")).unwrap();
+//!     let (cow, _old) = man.insert("cow".to_string(), Animal::new("bovine", "moo")).unwrap();
 //!
 //!     let description = |ticket: AnimalTicket| {
 //!         let (id, Animal { category, sound }) = man.get_ticket_full(ticket);
@@ -49,22 +53,69 @@
 //!     assert_eq!(description(cow), "A cow is a bovine and it goes moo!");
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! - `std` (enabled by default): Uses the standard library, and [`RandomState`] as the default hasher.
+//!   Without it the crate is `no_std`, only depending on `alloc`,
+//!   and the hasher type has to be always given explicitly.
+//! - `binary`: A compact binary format for reading and writing registries, see the `binary` module.
+//! - `rayon`: Parallel iterators using [rayon](https://crates.io/crates/rayon).
+//!
+//! [`RandomState`]: std::collections::hash_map::RandomState
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+pub mod array;
+#[cfg(feature = "binary")]
+pub mod binary;
+pub mod branded;
+pub mod cache;
+pub mod cursor;
+pub mod diff;
+pub mod entry;
+pub mod error;
+pub mod frozen;
 pub mod iter;
+pub mod sidecar;
+pub mod stats;
 
-use std::{
-    hash::Hash,
+use alloc::{string::String, sync::Arc, vec::Vec};
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{Display, Write},
+    hash::{BuildHasher, Hash, Hasher},
     marker::PhantomData,
-    ops::{Index, IndexMut},
+    ops::{Index, IndexMut, Range},
 };
+#[cfg(feature = "std")]
+use std::collections::{hash_map::RandomState, HashMap};
 
-use indexmap::{map::Entry, IndexMap};
+use indexmap::{
+    map::{Entry, Slice},
+    IndexMap,
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::IndexedParallelIterator;
 
+use branded::*;
+use cache::*;
+use cursor::*;
+use diff::*;
+use entry::*;
+use error::*;
+use frozen::*;
 use iter::*;
+use sidecar::*;
+use stats::*;
 
 /// A manager of arbitrary values with both identifier keys and index based tickets
 ///
-/// It is implemented with an [`IndexMap`] from the crate [indexmap](https://crates.io/crates/indexmap).
+/// It is implemented with an [`IndexMap`] from the crate [indexmap](https://crates.io/crates/indexmap),
+/// whose hasher can be chosen with the type parameter `S`.
 ///
 /// A ticket type should be used for only *one* registry manager value, otherwise using those
 /// ticket values might end up getting mixed with each other.
@@ -112,14 +163,31 @@ use iter::*;
 ///     assert_eq!(description(cow), "A cow is a bovine and it goes moo!");
 /// }
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct RegistryManager<T, Ticket, Identifier = String>
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct RegistryManager<T, Ticket, Identifier = String, S = RandomState>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    map: IndexMap<Identifier, T, S>,
+    no_grow: bool,
+    _phantom: PhantomData<fn() -> Ticket>,
+}
+
+/// A manager of arbitrary values with both identifier keys and index based tickets
+///
+/// Without the `std` feature there is no default hasher, so all of the type parameters have to be given.
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+pub struct RegistryManager<T, Ticket, Identifier, S>
 where
     Ticket: RegistryTicket,
     Identifier: Hash + Eq,
 {
-    map: IndexMap<Identifier, T>,
-    _phantom: PhantomData<*const Ticket>,
+    map: IndexMap<Identifier, T, S>,
+    no_grow: bool,
+    _phantom: PhantomData<fn() -> Ticket>,
 }
 
 /// A registry manager ticket
@@ -144,6 +212,45 @@ where
 ///
 /// The type of the wrapped value isn't checked by the auto-derive, but you should always use
 /// a rust builtin unsigned integer type, either smaller or of equal size to `usize`.
+///
+/// The auto-derive also implements the conversions `TryFrom<usize>` for the ticket and `From<Ticket>` for `usize`:
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::{error::IndexOutOfRange, RegistryTicket};
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// struct Ticket(u8);
+///
+/// let ticket = Ticket::try_from(5usize).unwrap();
+/// assert_eq!(usize::try_from(ticket), Ok(5));
+/// assert_eq!(usize::from(ticket), ticket.to_index());
+/// assert_eq!(Ticket::try_from(256usize), Err(IndexOutOfRange { index: 256 }));
+/// ```
+///
+/// The wrapped value can also be a `NonZero` unsigned integer, which makes `Option<Ticket>`
+/// the same size as the ticket. Then the index is stored offset by one.
+/// `NonZero` types are detected by their name, but it can also be forced with `#[registry_ticket(nonzero)]`:
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::RegistryTicket;
+/// use std::num::NonZeroU32;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// struct Ticket(NonZeroU32);
+///
+/// type Index = NonZeroU32;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// #[registry_ticket(nonzero)]
+/// struct AliasTicket(Index);
+///
+/// let first = Ticket::from_index(0).unwrap();
+/// assert_eq!(first.0.get(), 1);
+/// assert_eq!(first.to_index(), 0);
+/// assert_eq!(AliasTicket::from_index(0).unwrap().to_index(), 0);
+/// assert_eq!(std::mem::size_of::<Option<Ticket>>(), std::mem::size_of::<Ticket>());
+/// ```
 pub trait RegistryTicket: Sized {
     /// Converts the given index number into a ticket
     ///
@@ -155,14 +262,82 @@ pub trait RegistryTicket: Sized {
     ///
     /// This constructor must be deterministic for as long as it's used,
     /// ie. a specific input value always gives the same output.
+    /// If it succeeds for an index, it must also succeed for all of the smaller indices.
     fn from_index(index: usize) -> Option<Self>;
 
+    /// Converts the given index number into a ticket, without checking that it can be converted
+    ///
+    /// For code which has already made sure that the index is in range.
+    /// The registry manager itself always uses the checked [`from_index`](Self::from_index),
+    /// since a safe implementation of this trait can't be relied on to uphold its rules.
+    /// The default implementation unwraps [`from_index`](Self::from_index) unchecked.
+    ///
+    /// # Safety
+    ///
+    /// [`from_index`](Self::from_index) must return `Some` for the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::RegistryTicket;
+    /// # use std::num::NonZeroU8;
+    /// #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// struct Ticket(u8);
+    ///
+    /// #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// struct NonZeroTicket(NonZeroU8);
+    ///
+    /// for index in 0..=255 {
+    ///     let unchecked = unsafe { Ticket::from_index_unchecked(index) };
+    ///     assert_eq!(Some(unchecked), Ticket::from_index(index));
+    /// }
+    /// for index in 0..=254 {
+    ///     let unchecked = unsafe { NonZeroTicket::from_index_unchecked(index) };
+    ///     assert_eq!(Some(unchecked), NonZeroTicket::from_index(index));
+    /// }
+    /// ```
+    unsafe fn from_index_unchecked(index: usize) -> Self {
+        // SAFETY: the caller guarantees that the conversion succeeds
+        unsafe { Self::from_index(index).unwrap_unchecked() }
+    }
+
     /// Converts this ticket into the given index number
     ///
-    /// Must return the same value as it was constructed from in [`from_index`].
+    /// Must return the same value as it was constructed from in [`from_index`](Self::from_index).
     fn to_index(&self) -> usize;
+
+    /// Returns the largest index that can be converted into a ticket
+    ///
+    /// Returns `None` if the ticket type is unbounded, ie. it can represent (nearly) every `usize` index,
+    /// or if the limit isn't known. The default implementation always returns `None`.
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::RegistryTicket;
+    /// #[derive(Debug, Clone, Copy, RegistryTicket)]
+    /// struct SmallTicket(u8);
+    ///
+    /// #[derive(Debug, Clone, Copy, RegistryTicket)]
+    /// struct BigTicket(usize);
+    ///
+    /// #[derive(Debug, Clone, Copy, RegistryTicket)]
+    /// struct SmallNonZeroTicket(std::num::NonZeroU8);
+    ///
+    /// #[derive(Debug, Clone, Copy, RegistryTicket)]
+    /// struct BigNonZeroTicket(std::num::NonZeroUsize);
+    ///
+    /// assert_eq!(SmallTicket::max_index(), Some(255));
+    /// assert_eq!(BigTicket::max_index(), None);
+    /// assert_eq!(SmallNonZeroTicket::max_index(), Some(254));
+    /// assert_eq!(BigNonZeroTicket::max_index(), None);
+    /// ```
+    fn max_index() -> Option<usize> {
+        None
+    }
 }
 
+#[cfg(feature = "std")]
 impl<T, Ticket, Identifier> RegistryManager<T, Ticket, Identifier>
 where
     Ticket: RegistryTicket,
@@ -170,12 +345,182 @@ where
 {
     /// Creates a new empty registry manager
     pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<T, Ticket, Identifier, S> RegistryManager<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Creates a new empty registry manager which uses the given hasher
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            map: IndexMap::with_hasher(hasher),
+            no_grow: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new empty registry manager on top of the given map, keeping its allocation and hasher
+    ///
+    /// This allows setting up the map externally, for example with a specific capacity.
+    /// Any values already in the map are dropped,
+    /// use [`try_from_index_map`](Self::try_from_index_map) to keep them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # use indexmap::IndexMap;
+    /// # use std::collections::hash_map::RandomState;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut map = IndexMap::with_capacity_and_hasher(64, RandomState::new());
+    /// map.insert("old", 0);
+    ///
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new_with(map);
+    /// assert!(man.is_empty());
+    /// assert!(man.capacity() >= 64);
+    ///
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    /// assert_eq!(cat.to_index(), 0);
+    /// ```
+    pub fn new_with(mut map: IndexMap<Identifier, T, S>) -> Self {
+        map.clear();
+        Self {
+            map,
+            no_grow: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a new empty registry with a clone of this registry's hasher, and the same capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # use std::hash::BuildHasherDefault;
+    /// # use std::collections::hash_map::DefaultHasher;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// type Registry = RegistryManager<i32, Ticket, &'static str, BuildHasherDefault<DefaultHasher>>;
+    ///
+    /// let mut man = Registry::default();
+    /// man.reserve(10);
+    /// man.insert("cat", 1).unwrap();
+    ///
+    /// let mut spawned: Registry = man.spawn_empty();
+    /// assert!(spawned.is_empty());
+    /// assert!(spawned.capacity() >= 10);
+    ///
+    /// let (dog, _) = spawned.insert("dog", 2).unwrap();
+    /// assert_eq!(*spawned.get_ticket(dog), 2);
+    /// ```
+    pub fn spawn_empty(&self) -> Self
+    where
+        S: Clone,
+    {
+        Self {
+            map: IndexMap::with_capacity_and_hasher(self.capacity(), self.map.hasher().clone()),
+            no_grow: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Wraps the given map into a registry manager, keeping its order
+    ///
+    /// Returns [`RegistryFull`] if the map has more values than there are tickets.
+    /// Same as the `TryFrom<IndexMap>` implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::{error::RegistryFull, *};
+    /// # use indexmap::IndexMap;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u8);
+    /// let map: IndexMap<_, _> = [("cat", 1), ("dog", 2)].into_iter().collect();
+    /// let man = RegistryManager::<i32, Ticket, &str>::try_from_index_map(map).unwrap();
+    /// assert_eq!(man.get_ticket_of(&"dog").map(|t| t.to_index()), Some(1));
+    ///
+    /// let big: IndexMap<_, _> = (0..257).map(|i| (i, i)).collect();
+    /// let err = RegistryManager::<i32, Ticket, i32>::try_from_index_map(big);
+    /// assert_eq!(err, Err(RegistryFull));
+    /// ```
+    pub fn try_from_index_map(map: IndexMap<Identifier, T, S>) -> Result<Self, RegistryFull> {
+        Self::try_from(map)
+    }
+
+    /// Wraps the given map into a registry manager without checking its length
+    ///
+    /// # Safety
+    ///
+    /// Every index of the map must be convertible into a ticket,
+    /// ie. the map must not have more values than there are tickets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # use indexmap::IndexMap;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u8);
+    /// let map: IndexMap<_, _> = (0..256).map(|i| (i, i * 2)).collect();
+    /// // SAFETY: there are 256 tickets, and the map has 256 values
+    /// let man = unsafe { RegistryManager::<i32, Ticket, i32>::from_index_map_unchecked(map) };
+    /// assert_eq!(man.len(), 256);
+    /// assert_eq!(man.get_id(&255), Some(&510));
+    /// ```
+    pub unsafe fn from_index_map_unchecked(map: IndexMap<Identifier, T, S>) -> Self {
         Self {
-            map: IndexMap::new(),
+            map,
+            no_grow: false,
             _phantom: PhantomData,
         }
     }
 
+    /// Returns a reference to the internal index map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// assert!(man.as_index_map().is_empty());
+    ///
+    /// let (dog, _) = man.insert("dog", 2).unwrap();
+    /// man.insert("cat", 1).unwrap();
+    /// assert_eq!(man.as_index_map().get_index(dog.to_index()), Some((&"dog", &2)));
+    /// assert_eq!(man.as_index_map().get_index_of(&"cat"), Some(1));
+    /// ```
+    pub fn as_index_map(&self) -> &IndexMap<Identifier, T, S> {
+        &self.map
+    }
+
+    /// Returns a mutable reference to the internal index map
+    ///
+    /// # Safety
+    ///
+    /// The map must be left in a state where every index has a ticket,
+    /// and where the entries of all tickets handed out so far are still at their indices.
+    /// This means no removing, reordering, or inserting more values than there are tickets.
+    ///
+    /// Breaking this creates invalid tickets, with the consequences described on [`RegistryTicket`].
+    pub unsafe fn as_index_map_mut_unchecked(&mut self) -> &mut IndexMap<Identifier, T, S> {
+        &mut self.map
+    }
+
     /// Returns the number of stored values
     pub fn len(&self) -> usize {
         self.map.len()
@@ -186,6 +531,248 @@ where
         self.map.is_empty()
     }
 
+    /// Returns the number of values the registry can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns a snapshot of the registry's length and capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// assert_eq!(man.stats().load_factor, 0.0);
+    ///
+    /// man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    /// let stats = man.stats();
+    /// assert_eq!(stats.len, 3);
+    /// assert_eq!(stats.remaining_capacity, stats.capacity - 3);
+    /// assert!(stats.load_factor > 0.0 && stats.load_factor <= 1.0);
+    /// ```
+    pub fn stats(&self) -> RegistryStats {
+        let len = self.len();
+        let capacity = self.capacity();
+        RegistryStats {
+            len,
+            capacity,
+            remaining_capacity: capacity - len,
+            load_factor: if capacity == 0 {
+                0.0
+            } else {
+                len as f64 / capacity as f64
+            },
+        }
+    }
+
+    /// Returns the sum of the sizes of all of the values, as reported by [`ValueSize`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<String, Ticket, &str>::new();
+    /// assert_eq!(man.total_value_size(), 0);
+    ///
+    /// man.insert_many([("cat", "meow".to_string()), ("cow", "moo".to_string())]).unwrap();
+    /// assert_eq!(man.total_value_size(), 7);
+    ///
+    /// let mut numbers = RegistryManager::<u32, Ticket, &str>::new();
+    /// numbers.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    /// assert_eq!(numbers.total_value_size(), 8);
+    /// ```
+    pub fn total_value_size(&self) -> usize
+    where
+        T: ValueSize,
+    {
+        self.map.values().map(ValueSize::size).sum()
+    }
+
+    /// Reserves capacity for at least `additional` more values
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
+
+    /// Reserves capacity for at least `additional` more values,
+    /// if that many values can still get a ticket
+    ///
+    /// Otherwise returns the number of values that can still be inserted, without reserving anything.
+    /// Always reserves if the ticket type is unbounded, see [`RegistryTicket::max_index`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u8);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert_eq!(man.reserve_checked(255), Err(254));
+    /// assert_eq!(man.reserve_checked(254), Ok(()));
+    /// assert!(man.capacity() >= 256);
+    /// ```
+    pub fn reserve_checked(&mut self, additional: usize) -> Result<(), usize> {
+        if let Some(max) = Ticket::max_index() {
+            let remaining = max.saturating_add(1).saturating_sub(self.len());
+            if additional > remaining {
+                return Err(remaining);
+            }
+        }
+        self.map.reserve(additional);
+        Ok(())
+    }
+
+    /// Reserves capacity for as many values as there are tickets
+    ///
+    /// Does nothing if the ticket type is unbounded, see [`RegistryTicket::max_index`].
+    ///
+    /// This is only meant for small ticket types like `u8` and `u16`.
+    /// With `u32` or `u64` tickets it tries to allocate space for billions of entries,
+    /// which will most likely abort on allocation failure or panic on a capacity overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u8);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.reserve_to_capacity();
+    /// assert!(man.capacity() >= 256);
+    ///
+    /// #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// struct BigTicket(std::num::NonZeroUsize);
+    ///
+    /// let mut big = RegistryManager::<i32, BigTicket, &str>::new();
+    /// big.reserve_to_capacity();
+    /// assert_eq!(big.capacity(), 0);
+    /// ```
+    pub fn reserve_to_capacity(&mut self) {
+        if let Some(max) = Ticket::max_index() {
+            let total = max.saturating_add(1);
+            self.map.reserve(total.saturating_sub(self.len()));
+        }
+    }
+
+    /// Sets whether inserting is limited to the current capacity, so that the registry never reallocates
+    ///
+    /// While set, inserting a new identifier into a registry at its capacity fails
+    /// like the registry had run out of tickets, and the inserting methods don't reserve more space.
+    /// Overwriting existing values, and explicitly reserving with [`reserve`](Self::reserve),
+    /// still work as usual. Cloning keeps the setting, and reserves the same capacity while it's on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<usize, Ticket, usize>::new();
+    /// man.reserve(2);
+    /// man.set_no_grow(true);
+    /// assert!(man.is_no_grow());
+    ///
+    /// let capacity = man.capacity();
+    /// assert!(capacity >= 2);
+    /// for i in 0..capacity {
+    ///     man.insert(i, i).unwrap();
+    /// }
+    /// assert_eq!(man.insert(capacity, capacity), None);
+    /// assert_eq!(man.insert(0, 10), Some((man.get_ticket_of(&0).unwrap(), Some(0))));
+    /// assert_eq!(man.len(), capacity);
+    /// assert_eq!(man.capacity(), capacity);
+    ///
+    /// man.keep_first(1);
+    /// let mut clone = man.clone();
+    /// assert!(clone.is_no_grow());
+    /// assert!(clone.capacity() >= capacity);
+    /// for i in 1..clone.capacity() {
+    ///     clone.insert(i, i).unwrap();
+    /// }
+    /// assert_eq!(clone.insert(usize::MAX, 0), None);
+    ///
+    /// man.set_no_grow(false);
+    /// assert!(man.insert(capacity, capacity).is_some());
+    /// ```
+    pub fn set_no_grow(&mut self, on: bool) {
+        self.no_grow = on;
+    }
+
+    /// Returns whether inserting is limited to the current capacity, see [`set_no_grow`](Self::set_no_grow)
+    pub fn is_no_grow(&self) -> bool {
+        self.no_grow
+    }
+
+    /// Shrinks the capacity of the registry as much as possible
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit();
+    }
+
+    /// Shrinks the capacity of the registry with a lower limit
+    ///
+    /// The capacity will remain at least as large as both the length and `min_capacity`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.reserve(100);
+    /// let reserved = man.capacity();
+    /// man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// man.shrink_to(8);
+    /// assert!(man.capacity() >= 8);
+    /// assert!(man.capacity() < reserved);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.map.shrink_to(min_capacity);
+    }
+
+    /// Keeps only the first `n` values, dropping the rest
+    ///
+    /// The tickets of the kept values, ie. those with an index below `n`, stay valid,
+    /// but the tickets of the dropped values become invalid,
+    /// and will be handed out again for the next inserted values.
+    /// Does nothing if the registry has `n` or fewer values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("cat", 1), ("dog", 2), ("cow", 3), ("pig", 4)]).unwrap();
+    ///
+    /// man.keep_first(2);
+    /// assert_eq!(man.len(), 2);
+    /// assert_eq!(man.get_ticket_full(tickets[1]), (&"dog", &2));
+    /// assert!(!man.contains_id(&"cow"));
+    /// assert_eq!(man.try_get_ticket(tickets[2]), None);
+    ///
+    /// let (horse, _) = man.insert("horse", 5).unwrap();
+    /// assert_eq!(horse, tickets[2]);
+    /// ```
+    pub fn keep_first(&mut self, n: usize) {
+        self.map.truncate(n);
+    }
+
     /// Returns whether it is still possible to insert new values
     ///
     /// Equivalent to `[ticket type]::from_index(self.len()).is_some()`.
@@ -193,20 +780,96 @@ where
         Ticket::from_index(self.len()).is_some()
     }
 
+    /// Returns whether the registry has run out of tickets, so that no new values can be inserted
+    ///
+    /// The opposite of [`can_insert`](Self::can_insert).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u8);
+    /// let mut man = RegistryManager::<i32, Ticket, i32>::new();
+    /// man.insert_many((0..255).map(|i| (i, i))).unwrap();
+    /// assert!(!man.is_full());
+    ///
+    /// man.insert(255, 255).unwrap();
+    /// assert!(man.is_full());
+    /// assert!(!man.can_insert());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        !self.can_insert()
+    }
+
     /// Return if the given identifier (or something equal to it) exists in the registry
     pub fn contains_id(&self, id: &Identifier) -> bool {
         self.map.contains_key(id)
     }
 
+    /// Returns true if all of the given identifiers exist in the registry
+    ///
+    /// Stops at the first missing identifier, and returns true if no identifiers are given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert!(man.contains_all_ids(&["cat", "dog"]));
+    /// assert!(!man.contains_all_ids(&["cat", "cow"]));
+    /// assert!(man.contains_all_ids(&[]));
+    /// ```
+    pub fn contains_all_ids<'a>(&self, ids: impl IntoIterator<Item = &'a Identifier>) -> bool
+    where
+        Identifier: 'a,
+    {
+        ids.into_iter().all(|id| self.contains_id(id))
+    }
+
+    /// Returns true if any of the given identifiers exists in the registry
+    ///
+    /// Stops at the first existing identifier, and returns false if no identifiers are given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert!(man.contains_any_ids(&["cow", "dog"]));
+    /// assert!(!man.contains_any_ids(&["cow", "pig"]));
+    /// assert!(!man.contains_any_ids(&[]));
+    /// ```
+    pub fn contains_any_ids<'a>(&self, ids: impl IntoIterator<Item = &'a Identifier>) -> bool
+    where
+        Identifier: 'a,
+    {
+        ids.into_iter().any(|id| self.contains_id(id))
+    }
+
     /// Inserts the value to the registry with the given identifier
     ///
     /// Returns the ticket, and if the given identifier already had a value, returns that as well.
     ///
     /// Returns `None` if the index of the would be inserted value could not be converted
     /// into a ticket by [`RegistryTicket::from_index`], without modifying the internal map.
+    /// The same happens when inserting would grow the registry in the no-grow mode,
+    /// see [`set_no_grow`](Self::set_no_grow).
     pub fn insert(&mut self, id: Identifier, value: T) -> Option<(Ticket, Option<T>)> {
+        let limit = self.insert_limit();
         let entry = self.map.entry(id);
-        let ticket = Ticket::from_index(entry.index())?;
+        let ticket = Self::entry_ticket(entry.index(), limit)?;
 
         Some(match entry {
             Entry::Occupied(mut e) => (ticket, Some(e.insert(value))),
@@ -217,100 +880,2892 @@ where
         })
     }
 
-    /// Returns a reference to the value associated with the given id
-    pub fn get_id(&self, id: &Identifier) -> Option<&T> {
-        self.map.get(id)
+    /// Inserts the value to the registry with the given identifier,
+    /// returning the ticket, whether the entry was newly created, and the overwritten value if any
+    ///
+    /// Same as [`insert`](Self::insert), but with the freshness reported explicitly.
+    ///
+    /// Returns `None` if the index of the would be inserted value could not be converted
+    /// into a ticket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    ///
+    /// let (cat, fresh, old) = man.upsert("cat", 1).unwrap();
+    /// assert!(fresh);
+    /// assert_eq!(old, None);
+    ///
+    /// assert_eq!(man.upsert("cat", 2), Some((cat, false, Some(1))));
+    /// assert_eq!(man[cat], 2);
+    /// ```
+    pub fn upsert(&mut self, id: Identifier, value: T) -> Option<(Ticket, bool, Option<T>)> {
+        let (ticket, old) = self.insert(id, value)?;
+        Some((ticket, old.is_none(), old))
     }
 
-    /// Returns the ticket, a reference to the identifier,
-    /// and a reference the value associated with the given identifier
-    pub fn get_id_full(&self, id: &Identifier) -> Option<(Ticket, &Identifier, &T)> {
-        let (idx, id, val) = self.map.get_full(id)?;
-        Some((Ticket::from_index(idx)?, id, val))
-    }
+    /// Inserts the value returned by `make` to the registry with the given identifier
+    ///
+    /// The ticket is resolved first and given to `make`, so the value can store its own ticket.
+    /// If the identifier already has a value, `make` is called with the existing ticket,
+    /// and the old value is overwritten and returned, like with [`insert`](Self::insert).
+    ///
+    /// Returns `None` without calling `make`, if the index of the would be inserted value
+    /// could not be converted into a ticket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// struct Node {
+    ///     this: Ticket,
+    ///     parent: Option<Ticket>,
+    /// }
+    ///
+    /// let mut man = RegistryManager::<Node, Ticket, &str>::new();
+    /// let (root, _) = man.insert_with("root", |this| Node { this, parent: None }).unwrap();
+    /// let (leaf, _) = man
+    ///     .insert_with("leaf", |this| Node { this, parent: Some(root) })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(man[root].this, root);
+    /// assert_eq!(man[leaf].this, leaf);
+    /// assert_eq!(man[leaf].parent, Some(root));
+    ///
+    /// let (again, old) = man.insert_with("root", |this| Node { this, parent: None }).unwrap();
+    /// assert_eq!(again, root);
+    /// assert_eq!(old.map(|node| node.this), Some(root));
+    /// ```
+    pub fn insert_with(
+        &mut self,
+        id: Identifier,
+        make: impl FnOnce(Ticket) -> T,
+    ) -> Option<(Ticket, Option<T>)>
+    where
+        Ticket: Clone,
+    {
+        let limit = self.insert_limit();
+        let entry = self.map.entry(id);
+        let ticket = Self::entry_ticket(entry.index(), limit)?;
+        let value = make(ticket.clone());
 
-    /// Returns a mutable reference to the value associated with the given id
-    pub fn get_id_mut(&mut self, id: &Identifier) -> Option<&mut T> {
-        self.map.get_mut(id)
+        Some(match entry {
+            Entry::Occupied(mut e) => (ticket, Some(e.insert(value))),
+            Entry::Vacant(e) => {
+                e.insert(value);
+                (ticket, None)
+            }
+        })
     }
 
-    /// Returns the ticket, a reference to the identifier,
-    /// and a mutable reference the value associated with the given identifier
-    pub fn get_id_full_mut(&mut self, id: &Identifier) -> Option<(Ticket, &Identifier, &mut T)> {
-        let (idx, id, val) = self.map.get_full_mut(id)?;
-        Some((Ticket::from_index(idx)?, id, val))
+    /// Inserts all the given values to the registry, returning their tickets in the same order
+    ///
+    /// Values with already existing identifiers are overwritten like with [`insert`](Self::insert).
+    ///
+    /// Returns [`RegistryFull`] if the registry runs out of tickets.
+    /// In that case the values inserted before the failing one are left in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    ///
+    /// assert_eq!(man.get_ticket_of(&"cat"), Some(tickets[0]));
+    /// assert_eq!(man.get_ticket_of(&"dog"), Some(tickets[1]));
+    /// assert_eq!(man.get_ticket_of(&"cow"), Some(tickets[2]));
+    /// ```
+    pub fn insert_many(
+        &mut self,
+        items: impl IntoIterator<Item = (Identifier, T)>,
+    ) -> Result<Vec<Ticket>, RegistryFull> {
+        let items = items.into_iter();
+        let (lower, _) = items.size_hint();
+        if !self.no_grow {
+            self.map.reserve(lower);
+        }
+
+        let mut tickets = Vec::with_capacity(lower);
+        for (id, value) in items {
+            let (ticket, _old) = self.insert(id, value).ok_or(RegistryFull)?;
+            tickets.push(ticket);
+        }
+        Ok(tickets)
     }
 
-    /// Returns a reference to the value associated with the given ticket
+    /// Inserts clones of all of the entries of `other` to the registry, in ticket order
     ///
-    /// Assumes that the given ticket is valid.
-    pub fn get_ticket(&self, ticket: Ticket) -> &T {
-        &self.map[ticket.to_index()]
+    /// Like with [`insert`](Self::insert), the values of existing identifiers are overwritten,
+    /// but they keep their tickets. The other registry is left untouched.
+    ///
+    /// Returns [`RegistryFull`] if the registry runs out of tickets,
+    /// in which case the entries up to that point have been inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// other.insert_many([("dog", 2), ("cat", 10)]).unwrap();
+    /// let snapshot = other.clone();
+    ///
+    /// man.extend_cloned(&other).unwrap();
+    /// assert_eq!(man.clone().into_vec(), [("cat", 10), ("dog", 2)]);
+    /// assert_eq!(man.get_ticket_of(&"cat"), Some(cat));
+    /// assert_eq!(other, snapshot);
+    /// ```
+    pub fn extend_cloned(&mut self, other: &Self) -> Result<(), RegistryFull>
+    where
+        T: Clone,
+        Identifier: Clone,
+    {
+        if !self.no_grow {
+            self.map.reserve(other.len());
+        }
+        for (id, value) in &other.map {
+            self.insert(id.clone(), value.clone()).ok_or(RegistryFull)?;
+        }
+        Ok(())
     }
 
-    /// Returns references to the identifier and the value associated with the given ticket
+    /// Inserts new entries until the registry has `n` values,
+    /// generating the identifiers and values from their indices
     ///
-    /// Assumes that the given ticket is valid.
-    pub fn get_ticket_full(&self, ticket: Ticket) -> (&Identifier, &T) {
-        self.map.get_index(ticket.to_index()).unwrap()
+    /// Returns `None` if the registry runs out of tickets, or if a generated identifier already exists,
+    /// leaving the entries inserted before that in place.
+    /// Does nothing if the registry already has at least `n` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<usize, Ticket>::new();
+    /// man.grow_to(3, |i| format!("item{i}"), |i| i * 10).unwrap();
+    ///
+    /// for i in 0..3 {
+    ///     let ticket = man.get_ticket_of(&format!("item{i}")).unwrap();
+    ///     assert_eq!(ticket.to_index(), i);
+    ///     assert_eq!(*man.get_ticket(ticket), i * 10);
+    /// }
+    ///
+    /// assert_eq!(man.grow_to(5, |_| "item0".to_string(), |i| i), None);
+    /// assert_eq!(man.len(), 3);
+    /// ```
+    pub fn grow_to(
+        &mut self,
+        n: usize,
+        mut make_id: impl FnMut(usize) -> Identifier,
+        mut make_val: impl FnMut(usize) -> T,
+    ) -> Option<()> {
+        let reachable = Ticket::max_index().map_or(n, |max| n.min(max.saturating_add(1)));
+        if !self.no_grow {
+            self.map.reserve(reachable.saturating_sub(self.len()));
+        }
+
+        let limit = self.insert_limit();
+        for idx in self.len()..n {
+            Self::entry_ticket(idx, limit)?;
+            match self.map.entry(make_id(idx)) {
+                Entry::Occupied(_) => return None,
+                Entry::Vacant(e) => {
+                    e.insert(make_val(idx));
+                }
+            }
+        }
+        Some(())
     }
 
-    /// Returns a mutable reference to the value associated with the given ticket
+    /// Inserts the identifiers and values of previously saved `(ticket, identifier, value)` entries
     ///
-    /// Assumes that the given ticket is valid.
-    pub fn get_ticket_mut(&mut self, ticket: Ticket) -> &mut T {
-        &mut self.map[ticket.to_index()]
+    /// The given tickets aren't used, but in debug builds it's asserted that every entry gets back
+    /// the same ticket as it had, which catches entries in the wrong order.
+    ///
+    /// Returns [`RegistryFull`] if the registry runs out of tickets.
+    /// In that case the entries inserted before the failing one are left in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    /// let saved: Vec<_> = man.iter().map(|(ticket, id, value)| (ticket, *id, *value)).collect();
+    ///
+    /// let mut restored = RegistryManager::<i32, Ticket, &str>::new();
+    /// restored.restore(saved).unwrap();
+    /// assert_eq!(restored, man);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    /// let saved: Vec<_> = man.iter().map(|(ticket, id, value)| (ticket, *id, *value)).collect();
+    ///
+    /// let mut restored = RegistryManager::<i32, Ticket, &str>::new();
+    /// restored.restore(saved.into_iter().rev()).unwrap();
+    /// ```
+    pub fn restore(
+        &mut self,
+        entries: impl IntoIterator<Item = (Ticket, Identifier, T)>,
+    ) -> Result<(), RegistryFull> {
+        for (expected, id, value) in entries {
+            let (ticket, _old) = self.insert(id, value).ok_or(RegistryFull)?;
+            debug_assert_eq!(
+                ticket.to_index(),
+                expected.to_index(),
+                "restored entry got a different ticket than it was saved with",
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the ticket and a mutable reference to the value of the given identifier,
+    /// inserting the value returned by `make` if it doesn't exist yet
+    ///
+    /// Returns `None` if the index of the would be inserted value could not be converted
+    /// into a ticket, without calling `make`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<Vec<i32>, Ticket, &str>::new();
+    ///
+    /// let (cat, values) = man.get_or_insert_with("cat", Vec::new).unwrap();
+    /// values.push(1);
+    /// let (ticket, values) = man.get_or_insert_with("cat", Vec::new).unwrap();
+    /// values.push(2);
+    ///
+    /// assert_eq!(ticket, cat);
+    /// assert_eq!(man.get_ticket(cat), &[1, 2]);
+    /// ```
+    pub fn get_or_insert_with(
+        &mut self,
+        id: Identifier,
+        make: impl FnOnce() -> T,
+    ) -> Option<(Ticket, &mut T)> {
+        let limit = self.insert_limit();
+        let entry = self.map.entry(id);
+        let ticket = Self::entry_ticket(entry.index(), limit)?;
+        Some((ticket, entry.or_insert_with(make)))
+    }
+
+    /// Returns the ticket and a mutable reference to the value of the given identifier,
+    /// inserting the value returned by the fallible `make` if it doesn't exist yet
+    ///
+    /// If `make` fails, nothing is inserted and its error is returned in [`GetOrInsertError::Constructor`].
+    /// Returns [`GetOrInsertError::Full`] if the registry has run out of tickets, without calling `make`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::{error::GetOrInsertError, *};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u8);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    ///
+    /// let (cat, value) = man.get_or_try_insert_with("cat", || "1".parse()).unwrap();
+    /// assert_eq!((cat, *value), (man.get_ticket_of(&"cat").unwrap(), 1));
+    ///
+    /// let err = man.get_or_try_insert_with("dog", || "woof".parse()).unwrap_err();
+    /// assert!(matches!(err, GetOrInsertError::Constructor(_)));
+    /// assert!(!man.contains_id(&"dog"));
+    ///
+    /// let mut full = RegistryManager::<i32, Ticket, i32>::new();
+    /// full.insert_many((0..256).map(|i| (i, i))).unwrap();
+    /// let err = full.get_or_try_insert_with(256, || Ok::<_, ()>(256)).unwrap_err();
+    /// assert_eq!(err, GetOrInsertError::Full);
+    /// ```
+    pub fn get_or_try_insert_with<E, F>(
+        &mut self,
+        id: Identifier,
+        make: F,
+    ) -> Result<(Ticket, &mut T), GetOrInsertError<E>>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        let limit = self.insert_limit();
+        let entry = self.map.entry(id);
+        let ticket = Self::entry_ticket(entry.index(), limit).ok_or(GetOrInsertError::Full)?;
+
+        match entry {
+            Entry::Occupied(e) => Ok((ticket, e.into_mut())),
+            Entry::Vacant(e) => {
+                let value = make().map_err(GetOrInsertError::Constructor)?;
+                Ok((ticket, e.insert(value)))
+            }
+        }
+    }
+
+    /// Returns the ticket and a mutable reference to the value of the given identifier,
+    /// inserting the given value if it doesn't exist yet
+    ///
+    /// Unlike [`insert`](Self::insert), this never overwrites an existing value.
+    ///
+    /// Returns `None` if the index of the would be inserted value could not be converted
+    /// into a ticket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    ///
+    /// let (cat, value) = man.get_or_insert("cat", 1).unwrap();
+    /// assert_eq!(*value, 1);
+    ///
+    /// let (ticket, value) = man.get_or_insert("cat", 2).unwrap();
+    /// assert_eq!((ticket, *value), (cat, 1));
+    /// assert_eq!(*man.get_ticket(cat), 1);
+    /// ```
+    pub fn get_or_insert(&mut self, id: Identifier, value: T) -> Option<(Ticket, &mut T)> {
+        self.get_or_insert_with(id, || value)
+    }
+
+    /// Inserts the value to the registry if the given identifier doesn't exist yet, and returns its ticket
+    ///
+    /// Never overwrites an existing value. If the identifier already exists,
+    /// returns [`InsertError::Exists`] with the existing ticket,
+    /// and if the registry has run out of tickets, returns [`InsertError::Full`].
+    /// Either way the given value is handed back.
+    ///
+    /// This is the insert to use for append-only registries, where overwriting an existing identifier
+    /// is always a bug, since the collision has to be handled instead of the old value being dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::{error::InsertError, *};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u8);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    ///
+    /// let cat = man.insert_if_absent("cat", 1).unwrap();
+    /// assert_eq!(man.insert_if_absent("cat", 2), Err(InsertError::Exists(cat, 2)));
+    /// assert_eq!(man[cat], 1);
+    ///
+    /// let mut full = RegistryManager::<i32, Ticket, i32>::new();
+    /// full.insert_many((0..256).map(|i| (i, i))).unwrap();
+    /// assert_eq!(full.insert_if_absent(256, 256), Err(InsertError::Full(256)));
+    /// ```
+    pub fn insert_if_absent(
+        &mut self,
+        id: Identifier,
+        value: T,
+    ) -> Result<Ticket, InsertError<Ticket, T>> {
+        let limit = self.insert_limit();
+        match self.map.entry(id) {
+            Entry::Occupied(e) => match Ticket::from_index(e.index()) {
+                Some(ticket) => Err(InsertError::Exists(ticket, value)),
+                None => Err(InsertError::Full(value)),
+            },
+            Entry::Vacant(e) => match Self::entry_ticket(e.index(), limit) {
+                Some(ticket) => {
+                    e.insert(value);
+                    Ok(ticket)
+                }
+                None => Err(InsertError::Full(value)),
+            },
+        }
+    }
+
+    /// Replaces the value of an identifier which already exists in the registry
+    ///
+    /// Returns its ticket, which stays the same, and the old value.
+    /// Returns `None` without inserting anything if the identifier doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// assert_eq!(man.replace(&"cat", 2), Some((cat, 1)));
+    /// assert_eq!(man[cat], 2);
+    ///
+    /// assert_eq!(man.replace(&"dog", 3), None);
+    /// assert!(!man.contains_id(&"dog"));
+    /// ```
+    pub fn replace(&mut self, id: &Identifier, value: T) -> Option<(Ticket, T)> {
+        let (idx, _, old) = self.map.get_full_mut(id)?;
+        Some((Ticket::from_index(idx)?, core::mem::replace(old, value)))
+    }
+
+    /// Renames the identifier `old` to `new`, keeping its ticket and value
+    ///
+    /// Returns [`RenameError::NotFound`] if `old` doesn't exist,
+    /// and [`RenameError::AlreadyExists`] if `new` is already used by another entry.
+    /// Either way the registry is left untouched and `new` is handed back.
+    /// Renaming an identifier to an equal one replaces the stored identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::{error::RenameError, *};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    /// let (dog, _) = man.insert("dog", 2).unwrap();
+    ///
+    /// assert_eq!(man.rename(&"cat", "kitten"), Ok(cat));
+    /// assert_eq!(man.get_ticket_full(cat), (&"kitten", &1));
+    /// assert!(!man.contains_id(&"cat"));
+    ///
+    /// assert_eq!(man.rename(&"cat", "tiger"), Err(RenameError::NotFound("tiger")));
+    /// assert_eq!(man.rename(&"kitten", "dog"), Err(RenameError::AlreadyExists("dog")));
+    /// assert_eq!(man.rename(&"dog", "dog"), Ok(dog));
+    /// assert_eq!(man.get_ticket_of(&"dog"), Some(dog));
+    /// ```
+    pub fn rename(
+        &mut self,
+        old: &Identifier,
+        new: Identifier,
+    ) -> Result<Ticket, RenameError<Identifier>> {
+        let Some(idx) = self.map.get_index_of(old) else {
+            return Err(RenameError::NotFound(new));
+        };
+        let ticket = Ticket::from_index(idx).expect("every index of the registry has a ticket");
+        self.map
+            .replace_index(idx, new)
+            .map_err(|(_, new)| RenameError::AlreadyExists(new))?;
+        Ok(ticket)
+    }
+
+    /// Returns the ticket, whether the entry was newly created,
+    /// and a mutable reference to the value of the given identifier,
+    /// inserting a default value if it doesn't exist yet
+    ///
+    /// Returns `None` if the index of the would be inserted value could not be converted
+    /// into a ticket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    ///
+    /// let (cat, fresh, value) = man.resolve("cat").unwrap();
+    /// assert!(fresh);
+    /// *value = 5;
+    ///
+    /// let (ticket, fresh, value) = man.resolve("cat").unwrap();
+    /// assert!(!fresh);
+    /// assert_eq!((ticket, *value), (cat, 5));
+    /// ```
+    pub fn resolve(&mut self, id: Identifier) -> Option<(Ticket, bool, &mut T)>
+    where
+        T: Default,
+    {
+        let limit = self.insert_limit();
+        let entry = self.map.entry(id);
+        let ticket = Self::entry_ticket(entry.index(), limit)?;
+
+        Some(match entry {
+            Entry::Occupied(e) => (ticket, false, e.into_mut()),
+            Entry::Vacant(e) => (ticket, true, e.insert(T::default())),
+        })
+    }
+
+    /// Returns the ticket of the given identifier and whether the entry was newly created,
+    /// inserting a default value if it doesn't exist yet
+    ///
+    /// Like [`resolve`](Self::resolve), but without borrowing the value.
+    /// Returns `None` only if the registry has run out of tickets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<(), Ticket, &str>::new();
+    ///
+    /// let (cat, fresh) = man.ticket_or_new("cat").unwrap();
+    /// assert!(fresh);
+    /// assert_eq!(man.ticket_or_new("cat"), Some((cat, false)));
+    /// ```
+    pub fn ticket_or_new(&mut self, id: Identifier) -> Option<(Ticket, bool)>
+    where
+        T: Default,
+    {
+        self.resolve(id).map(|(ticket, fresh, _)| (ticket, fresh))
+    }
+
+    /// Returns the ticket and a mutable reference to the value of a borrowed form of the identifier,
+    /// inserting a new value if it doesn't exist yet
+    ///
+    /// `to_owned` and `make` are only called when the identifier doesn't exist,
+    /// so for example `String` identifiers don't have to be allocated for lookups.
+    /// `to_owned` must return an identifier equal to `id`. If it doesn't and the returned identifier
+    /// already has a value, that value is returned without overwriting it, and debug builds panic.
+    ///
+    /// Returns `None` if the new value's index can't be converted into a ticket,
+    /// without calling either closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket>::new();
+    ///
+    /// let (cat, value) = man.get_or_insert_with_ref("cat", || "cat".to_string(), || 1).unwrap();
+    /// *value += 1;
+    ///
+    /// let (ticket, value) = man
+    ///     .get_or_insert_with_ref("cat", || unreachable!(), || unreachable!())
+    ///     .unwrap();
+    /// assert_eq!(ticket, cat);
+    /// assert_eq!(*value, 2);
+    /// ```
+    pub fn get_or_insert_with_ref<Q>(
+        &mut self,
+        id: &Q,
+        to_owned: impl FnOnce() -> Identifier,
+        make: impl FnOnce() -> T,
+    ) -> Option<(Ticket, &mut T)>
+    where
+        Identifier: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(idx) = self.map.get_index_of(id) {
+            let ticket = Ticket::from_index(idx)?;
+            return Some((ticket, &mut self.map[idx]));
+        }
+
+        let limit = self.insert_limit();
+        Self::entry_ticket(self.map.len(), limit)?;
+        let entry = self.map.entry(to_owned());
+        debug_assert!(
+            matches!(entry, Entry::Vacant(_)),
+            "the owned identifier must be equal to the borrowed one"
+        );
+        let ticket = Self::entry_ticket(entry.index(), limit)?;
+        Some((ticket, entry.or_insert_with(make)))
+    }
+
+    /// Returns a reference to the value associated with the given id
+    pub fn get_id(&self, id: &Identifier) -> Option<&T> {
+        self.map.get(id)
+    }
+
+    /// Returns the ticket, a reference to the identifier,
+    /// and a reference the value associated with the given identifier
+    pub fn get_id_full(&self, id: &Identifier) -> Option<(Ticket, &Identifier, &T)> {
+        let (idx, id, val) = self.map.get_full(id)?;
+        Some((Ticket::from_index(idx)?, id, val))
+    }
+
+    /// Returns a mutable reference to the value associated with the given id
+    pub fn get_id_mut(&mut self, id: &Identifier) -> Option<&mut T> {
+        self.map.get_mut(id)
+    }
+
+    /// Returns the ticket, a reference to the identifier,
+    /// and a mutable reference the value associated with the given identifier
+    pub fn get_id_full_mut(&mut self, id: &Identifier) -> Option<(Ticket, &Identifier, &mut T)> {
+        let (idx, id, val) = self.map.get_full_mut(id)?;
+        Some((Ticket::from_index(idx)?, id, val))
+    }
+
+    /// Returns the ticket and a mutable reference to the value associated with the given identifier
+    ///
+    /// Like [`get_id_full_mut`](Self::get_id_full_mut), but without the identifier reference,
+    /// so nothing but the value keeps the registry borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # use std::collections::HashMap;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket>::new();
+    /// let (cat, _) = man.insert("cat".to_string(), 1).unwrap();
+    /// let mut bonuses = HashMap::from([("cat".to_string(), 10)]);
+    ///
+    /// let id = "cat".to_string();
+    /// let (ticket, value) = man.get_id_value_mut(&id).unwrap();
+    /// *value += bonuses.remove(&id).unwrap();
+    ///
+    /// assert_eq!(ticket, cat);
+    /// assert_eq!(*man.get_ticket(cat), 11);
+    /// ```
+    pub fn get_id_value_mut(&mut self, id: &Identifier) -> Option<(Ticket, &mut T)> {
+        let (idx, _, val) = self.map.get_full_mut(id)?;
+        Some((Ticket::from_index(idx)?, val))
+    }
+
+    /// Returns the ticket, a reference to the identifier,
+    /// and a reference to the value associated with a borrowed form of the identifier
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket>::new();
+    /// let (cat, _) = man.insert("cat".to_string(), 1).unwrap();
+    ///
+    /// let (ticket, id, value) = man.get_id_full_q("cat").unwrap();
+    /// assert_eq!((ticket, id.as_str(), *value), (cat, "cat", 1));
+    /// assert!(man.get_id_full_q("dog").is_none());
+    /// ```
+    pub fn get_id_full_q<Q>(&self, id: &Q) -> Option<(Ticket, &Identifier, &T)>
+    where
+        Identifier: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (idx, id, val) = self.map.get_full(id)?;
+        Some((Ticket::from_index(idx)?, id, val))
+    }
+
+    /// Returns the ticket, a reference to the identifier,
+    /// and a mutable reference to the value associated with a borrowed form of the identifier
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket>::new();
+    /// let (cat, _) = man.insert("cat".to_string(), 1).unwrap();
+    ///
+    /// let (ticket, _, value) = man.get_id_full_mut_q("cat").unwrap();
+    /// *value += 1;
+    /// assert_eq!(ticket, cat);
+    /// assert_eq!(*man.get_ticket(cat), 2);
+    /// assert!(man.get_id_full_mut_q("dog").is_none());
+    /// ```
+    pub fn get_id_full_mut_q<Q>(&mut self, id: &Q) -> Option<(Ticket, &Identifier, &mut T)>
+    where
+        Identifier: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (idx, id, val) = self.map.get_full_mut(id)?;
+        Some((Ticket::from_index(idx)?, id, val))
+    }
+
+    /// Returns a reference to the value associated with the given ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the ticket's index and the registry's length if the ticket is out of bounds.
+    /// See [`get_ticket_unchecked`](Self::get_ticket_unchecked) for a variant without the check.
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    ///
+    /// let panic = std::panic::catch_unwind(|| *man.get_ticket(tickets[2])).unwrap_err();
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "ticket index 2 out of bounds (len 1)");
+    /// ```
+    pub fn get_ticket(&self, ticket: Ticket) -> &T {
+        self.expect_index(ticket.to_index()).1
+    }
+
+    /// Returns references to the identifier and the value associated with the given ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the ticket's index and the registry's length if the ticket is out of bounds.
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// let panic = std::panic::catch_unwind(|| man.get_ticket_full(tickets[1])).unwrap_err();
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "ticket index 1 out of bounds (len 1)");
+    /// ```
+    pub fn get_ticket_full(&self, ticket: Ticket) -> (&Identifier, &T) {
+        self.expect_index(ticket.to_index())
+    }
+
+    /// Returns a mutable reference to the value associated with the given ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    pub fn get_ticket_mut(&mut self, ticket: Ticket) -> &mut T {
+        self.expect_index_mut(ticket.to_index()).1
     }
 
     /// Returns a reference to the identifier and a mutable reference to the value
     /// associated with the given ticket
     ///
-    /// Assumes that the given ticket is valid.
-    pub fn get_ticket_full_mut(&mut self, ticket: Ticket) -> (&Identifier, &mut T) {
-        let (id, val) = self.map.get_index_mut(ticket.to_index()).unwrap();
-        (id, val)
+    /// Assumes that the given ticket is valid.
+    pub fn get_ticket_full_mut(&mut self, ticket: Ticket) -> (&Identifier, &mut T) {
+        self.expect_index_mut(ticket.to_index())
+    }
+
+    /// Returns a reference to the value associated with the given ticket, without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// The ticket must be in bounds, ie. its index must be less than [`len`](Self::len).
+    /// This holds for every ticket handed out by this registry, as long as the tickets
+    /// haven't been invalidated by methods like [`keep_first`](Self::keep_first).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// type Registry = RegistryManager<i32, Ticket, &'static str>;
+    ///
+    /// fn get_checked(man: &Registry, ticket: Ticket) -> Option<&i32> {
+    ///     // SAFETY: the ticket was just checked to be in bounds
+    ///     (ticket.to_index() < man.len()).then(|| unsafe { man.get_ticket_unchecked(ticket) })
+    /// }
+    ///
+    /// let mut man = Registry::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = Registry::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert_eq!(get_checked(&man, cat), Some(&1));
+    /// assert_eq!(get_checked(&man, tickets[1]), None);
+    /// ```
+    pub unsafe fn get_ticket_unchecked(&self, ticket: Ticket) -> &T {
+        // SAFETY: the caller guarantees that the ticket is in bounds
+        unsafe { self.map.get_index(ticket.to_index()).unwrap_unchecked().1 }
+    }
+
+    /// Returns a mutable reference to the value associated with the given ticket, without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// The ticket must be in bounds, see [`get_ticket_unchecked`](Self::get_ticket_unchecked).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// // SAFETY: the ticket was handed out by this registry, which hasn't been truncated
+    /// *unsafe { man.get_ticket_unchecked_mut(cat) } += 1;
+    /// assert_eq!(man[cat], 2);
+    /// ```
+    pub unsafe fn get_ticket_unchecked_mut(&mut self, ticket: Ticket) -> &mut T {
+        // SAFETY: the caller guarantees that the ticket is in bounds
+        unsafe {
+            self.map
+                .get_index_mut(ticket.to_index())
+                .unwrap_unchecked()
+                .1
+        }
+    }
+
+    /// Returns the number of values the registry can hold before inserting would have to grow it
+    ///
+    /// Only limited while the no-grow mode is on, see [`set_no_grow`](Self::set_no_grow).
+    fn insert_limit(&self) -> usize {
+        if self.no_grow {
+            self.map.capacity()
+        } else {
+            usize::MAX
+        }
+    }
+
+    /// Converts the index of an entry into a ticket, failing if it isn't below the insertion limit
+    ///
+    /// Existing entries are always below the limit, so this only makes new entries fail.
+    fn entry_ticket(index: usize, limit: usize) -> Option<Ticket> {
+        if index < limit {
+            Ticket::from_index(index)
+        } else {
+            None
+        }
+    }
+
+    /// Checks that the last index can be converted into a ticket, so that every index has one
+    ///
+    /// This always holds, since every way of filling the registry checks it,
+    /// but the iterators rely on it when producing tickets.
+    fn assert_tickets_fit(&self) {
+        assert!(
+            self.is_empty() || Ticket::from_index(self.len() - 1).is_some(),
+            "the registry has more values than there are tickets"
+        );
+    }
+
+    /// Returns the entry at the given index, panicking with a descriptive message if it's out of bounds
+    fn expect_index(&self, index: usize) -> (&Identifier, &T) {
+        self.map
+            .get_index(index)
+            .unwrap_or_else(|| index_out_of_bounds(index, self.len()))
+    }
+
+    /// Returns the entry at the given index, panicking with a descriptive message if it's out of bounds
+    fn expect_index_mut(&mut self, index: usize) -> (&Identifier, &mut T) {
+        let len = self.len();
+        let (id, val) = self
+            .map
+            .get_index_mut(index)
+            .unwrap_or_else(|| index_out_of_bounds(index, len));
+        (id, val)
+    }
+
+    /// Returns the ticket, a reference to the identifier,
+    /// and a mutable reference to the value associated with the given ticket
+    ///
+    /// Returns `None` if the ticket is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// let (ticket, id, value) = man.try_get_ticket_full_mut(cat).unwrap();
+    /// *value += 1;
+    /// assert_eq!((ticket, *id), (cat, "cat"));
+    /// assert_eq!(*man.get_ticket(cat), 2);
+    ///
+    /// assert!(man.try_get_ticket_full_mut(tickets[1]).is_none());
+    /// ```
+    pub fn try_get_ticket_full_mut(
+        &mut self,
+        ticket: Ticket,
+    ) -> Option<(Ticket, &Identifier, &mut T)> {
+        let (id, val) = self.map.get_index_mut(ticket.to_index())?;
+        Some((ticket, id, val))
+    }
+
+    /// Returns references to the identifier and the value associated with the given ticket
+    ///
+    /// Returns [`InvalidTicket`] if the ticket is out of bounds,
+    /// which makes this convenient to use with the `?` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::{error::InvalidTicket, *};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// let (id, value) = man.get_ticket_full_mut_checked(cat).unwrap();
+    /// *value += 1;
+    /// assert_eq!(*id, "cat");
+    /// assert_eq!(man[cat], 2);
+    ///
+    /// let err = man.get_ticket_full_mut_checked(tickets[1]).unwrap_err();
+    /// assert_eq!(err, InvalidTicket { index: 1, len: 1 });
+    /// ```
+    pub fn get_ticket_full_mut_checked(
+        &mut self,
+        ticket: Ticket,
+    ) -> Result<(&Identifier, &mut T), InvalidTicket> {
+        let (index, len) = (ticket.to_index(), self.len());
+        self.map
+            .get_index_mut(index)
+            .ok_or(InvalidTicket { index, len })
+    }
+
+    /// Returns a handle to the entry of the given ticket, or `None` if the ticket is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("cat", 1), ("dog", -2)]).unwrap();
+    ///
+    /// for ticket in tickets {
+    ///     let mut entry = man.ticket_entry(ticket).unwrap();
+    ///     if *entry.get() < 0 && *entry.id() == "dog" {
+    ///         *entry.get_mut() = 0;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(man.get_id(&"cat"), Some(&1));
+    /// assert_eq!(man.get_id(&"dog"), Some(&0));
+    /// ```
+    pub fn ticket_entry(&mut self, ticket: Ticket) -> Option<TicketEntry<'_, T, Identifier>> {
+        let (id, value) = self.map.get_index_mut(ticket.to_index())?;
+        Some(TicketEntry { id, value })
+    }
+
+    /// Returns a handle to the entry of the given identifier, which may be vacant
+    ///
+    /// Like [`IndexMap::entry`], but the handle knows the entry's ticket,
+    /// and refuses to insert values which wouldn't get one. See [`TicketEntryApi`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u8);
+    /// let mut man = RegistryManager::<i32, Ticket, i32>::new();
+    /// let (cat, _) = man.insert(0, 1).unwrap();
+    ///
+    /// let entry = man.id_entry(0);
+    /// assert!(entry.is_occupied());
+    /// assert_eq!(entry.ticket(), Some(cat));
+    /// assert_eq!(entry.or_insert(10), Some((cat, &mut 1)));
+    ///
+    /// let entry = man.id_entry(1);
+    /// let dog = entry.ticket().unwrap();
+    /// let (ticket, value) = entry.or_insert_with(|| 2).unwrap();
+    /// assert_eq!(ticket, dog);
+    /// *value += 1;
+    /// assert_eq!(man.get_ticket_full(dog), (&1, &3));
+    ///
+    /// let (cow, value) = man.id_entry(2).or_insert(3).unwrap();
+    /// assert_eq!((cow.to_index(), *value), (2, 3));
+    ///
+    /// man.grow_to(256, |i| i as i32, |_| 0).unwrap();
+    /// let entry = man.id_entry(256);
+    /// assert_eq!(entry.ticket(), None);
+    /// assert_eq!(entry.or_insert(0), None);
+    /// assert!(!man.contains_id(&256));
+    /// ```
+    pub fn id_entry(&mut self, id: Identifier) -> TicketEntryApi<'_, T, Ticket, Identifier> {
+        let limit = self.insert_limit();
+        let entry = self.map.entry(id);
+        let ticket = Self::entry_ticket(entry.index(), limit);
+        TicketEntryApi { entry, ticket }
+    }
+
+    /// Returns a cursor pointing to the entry of the given ticket,
+    /// or `None` if the ticket is out of bounds
+    ///
+    /// See [`Cursor`].
+    pub fn cursor_at(&self, ticket: Ticket) -> Option<Cursor<'_, T, Ticket, Identifier, S>> {
+        let index = ticket.to_index();
+        (index < self.len()).then_some(Cursor {
+            registry: self,
+            index,
+        })
+    }
+
+    /// Returns a handle bundling the given ticket with its identifier and value
+    ///
+    /// Assumes that the given ticket is valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ticket is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert("cat", 1).unwrap();
+    /// let (dog, _) = man.insert("dog", 2).unwrap();
+    ///
+    /// let entry = man.ticket_ref(dog);
+    /// assert_eq!(entry.ticket(), dog);
+    /// assert_eq!(*entry.id(), "dog");
+    /// assert_eq!(*entry.value(), 2);
+    /// ```
+    pub fn ticket_ref(&self, ticket: Ticket) -> TicketRef<'_, T, Ticket, Identifier> {
+        let (id, value) = self.expect_index(ticket.to_index());
+        TicketRef { ticket, id, value }
+    }
+
+    /// Returns a reference to the value associated with the given ticket
+    ///
+    /// Returns `None` if the ticket is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert_eq!(man.try_get_ticket(cat), Some(&1));
+    /// assert_eq!(man.try_get_ticket(tickets[1]), None);
+    /// ```
+    pub fn try_get_ticket(&self, ticket: Ticket) -> Option<&T> {
+        self.map.get_index(ticket.to_index()).map(|(_, val)| val)
+    }
+
+    /// Returns a mutable reference to the value associated with the given ticket
+    ///
+    /// Returns `None` if the ticket is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// *man.try_get_ticket_mut(cat).unwrap() += 1;
+    /// assert_eq!(*man.get_ticket(cat), 2);
+    /// assert_eq!(man.try_get_ticket_mut(tickets[1]), None);
+    /// ```
+    pub fn try_get_ticket_mut(&mut self, ticket: Ticket) -> Option<&mut T> {
+        self.map
+            .get_index_mut(ticket.to_index())
+            .map(|(_, val)| val)
+    }
+
+    /// Returns a reference to the value associated with the given ticket, taking the ticket by reference
+    ///
+    /// Same as [`get_ticket`](Self::get_ticket), but for ticket types which aren't [`Copy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager::*;
+    /// #[derive(Debug, PartialEq)]
+    /// struct BigTicket(u128);
+    ///
+    /// impl RegistryTicket for BigTicket {
+    ///     fn from_index(index: usize) -> Option<Self> {
+    ///         Some(Self(index as u128))
+    ///     }
+    ///     fn to_index(&self) -> usize {
+    ///         self.0 as usize
+    ///     }
+    /// }
+    ///
+    /// let mut man = RegistryManager::<i32, BigTicket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    /// let (dog, _) = man.insert("dog", 2).unwrap();
+    ///
+    /// *man.get_ticket_mut_ref(&cat) += 10;
+    /// assert_eq!(man.get_ticket_ref(&cat), &11);
+    /// assert_eq!(man.get_ticket_full_ref(&dog), (&"dog", &2));
+    /// assert_eq!(man.try_get_ticket_ref(&dog), Some(&2));
+    ///
+    /// man.keep_first(1);
+    /// assert_eq!(man.try_get_ticket_ref(&dog), None);
+    /// ```
+    pub fn get_ticket_ref(&self, ticket: &Ticket) -> &T {
+        self.expect_index(ticket.to_index()).1
+    }
+
+    /// Returns references to the identifier and the value associated with the given ticket,
+    /// taking the ticket by reference
+    ///
+    /// Same as [`get_ticket_full`](Self::get_ticket_full), but for ticket types which aren't [`Copy`].
+    pub fn get_ticket_full_ref(&self, ticket: &Ticket) -> (&Identifier, &T) {
+        self.expect_index(ticket.to_index())
+    }
+
+    /// Returns a mutable reference to the value associated with the given ticket,
+    /// taking the ticket by reference
+    ///
+    /// Same as [`get_ticket_mut`](Self::get_ticket_mut), but for ticket types which aren't [`Copy`].
+    pub fn get_ticket_mut_ref(&mut self, ticket: &Ticket) -> &mut T {
+        self.expect_index_mut(ticket.to_index()).1
+    }
+
+    /// Returns a reference to the value associated with the given ticket, taking the ticket by reference
+    ///
+    /// Same as [`try_get_ticket`](Self::try_get_ticket), but for ticket types which aren't [`Copy`].
+    pub fn try_get_ticket_ref(&self, ticket: &Ticket) -> Option<&T> {
+        self.map.get_index(ticket.to_index()).map(|(_, val)| val)
+    }
+
+    /// Returns the ticket, a reference to the identifier,
+    /// and a reference to the value associated with the given ticket
+    ///
+    /// Returns `None` if the ticket is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert_eq!(man.try_get_ticket_full(cat), Some((cat, &"cat", &1)));
+    /// assert_eq!(man.try_get_ticket_full(tickets[1]), None);
+    /// ```
+    pub fn try_get_ticket_full(&self, ticket: Ticket) -> Option<(Ticket, &Identifier, &T)> {
+        let (id, val) = self.map.get_index(ticket.to_index())?;
+        Some((ticket, id, val))
+    }
+
+    /// Returns the ticket with clones of the identifier and the value associated with it
+    ///
+    /// Returns `None` if the ticket is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<Vec<i32>, Ticket>::new();
+    /// let (cat, _) = man.insert("cat".to_string(), vec![1]).unwrap();
+    ///
+    /// let (ticket, id, mut value) = man.cloned_entry(cat).unwrap();
+    /// value.push(2);
+    /// man[cat].push(3);
+    ///
+    /// assert_eq!((ticket, id.as_str()), (cat, "cat"));
+    /// assert_eq!(value, [1, 2]);
+    /// assert_eq!(man[cat], [1, 3]);
+    ///
+    /// let handle = std::thread::spawn(move || (id, value));
+    /// assert_eq!(handle.join().unwrap().0, "cat");
+    /// ```
+    pub fn cloned_entry(&self, ticket: Ticket) -> Option<(Ticket, Identifier, T)>
+    where
+        Identifier: Clone,
+        T: Clone,
+    {
+        let (id, val) = self.map.get_index(ticket.to_index())?;
+        Some((ticket, id.clone(), val.clone()))
+    }
+
+    /// Returns the ticket and a reference to the value associated with the given ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// assert_eq!(man.get_ticket_value(cat), (cat, &1));
+    /// ```
+    pub fn get_ticket_value(&self, ticket: Ticket) -> (Ticket, &T) {
+        let (_, val) = self.expect_index(ticket.to_index());
+        (ticket, val)
+    }
+
+    /// Returns the ticket and a mutable reference to the value associated with the given ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let (ticket, value) = man.get_ticket_value_mut(cat);
+    /// *value += 1;
+    /// assert_eq!(ticket, cat);
+    /// assert_eq!(*man.get_ticket(cat), 2);
+    /// ```
+    pub fn get_ticket_value_mut(&mut self, ticket: Ticket) -> (Ticket, &mut T) {
+        let (_, val) = self.expect_index_mut(ticket.to_index());
+        (ticket, val)
+    }
+
+    /// Returns the ticket and a reference to the value associated with the given ticket
+    ///
+    /// Returns `None` if the ticket is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert_eq!(man.try_get_ticket_value(cat), Some((cat, &1)));
+    /// assert_eq!(man.try_get_ticket_value(tickets[1]), None);
+    /// ```
+    pub fn try_get_ticket_value(&self, ticket: Ticket) -> Option<(Ticket, &T)> {
+        let (_, val) = self.map.get_index(ticket.to_index())?;
+        Some((ticket, val))
+    }
+
+    /// Returns the ticket and a mutable reference to the value associated with the given ticket
+    ///
+    /// Returns `None` if the ticket is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// let (ticket, value) = man.try_get_ticket_value_mut(cat).unwrap();
+    /// *value += 1;
+    /// assert_eq!(ticket, cat);
+    /// assert_eq!(*man.get_ticket(cat), 2);
+    /// assert_eq!(man.try_get_ticket_value_mut(tickets[1]), None);
+    /// ```
+    pub fn try_get_ticket_value_mut(&mut self, ticket: Ticket) -> Option<(Ticket, &mut T)> {
+        let (_, val) = self.map.get_index_mut(ticket.to_index())?;
+        Some((ticket, val))
+    }
+
+    /// Returns references to the values associated with each of the given tickets
+    ///
+    /// Returns `None` if any of the tickets is out of bounds.
+    /// The same ticket may be given more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    /// let (dog, _) = man.insert("dog", 2).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    ///
+    /// assert_eq!(man.get_many([dog, cat, dog]), Some([&2, &1, &2]));
+    /// assert_eq!(man.get_many([cat, tickets[2]]), None);
+    /// ```
+    pub fn get_many<const N: usize>(&self, tickets: [Ticket; N]) -> Option<[&T; N]> {
+        if tickets.iter().any(|ticket| ticket.to_index() >= self.len()) {
+            return None;
+        }
+        Some(tickets.map(|ticket| &self.map[ticket.to_index()]))
+    }
+
+    /// Checks that all of the given tickets point to a value in the registry
+    ///
+    /// Returns the first ticket which is out of bounds as the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    /// let (dog, _) = man.insert("dog", 2).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    ///
+    /// assert_eq!(man.validate(&[cat, dog, cat]), Ok(()));
+    /// assert_eq!(man.validate(&[dog, tickets[2], cat]), Err(tickets[2]));
+    /// ```
+    pub fn validate<'a>(&self, tickets: impl IntoIterator<Item = &'a Ticket>) -> Result<(), Ticket>
+    where
+        Ticket: 'a + Copy,
+    {
+        match tickets
+            .into_iter()
+            .find(|ticket| ticket.to_index() >= self.len())
+        {
+            Some(ticket) => Err(*ticket),
+            None => Ok(()),
+        }
+    }
+
+    /// Replaces the value associated with the given ticket, returning the old value
+    ///
+    /// Returns `None` without dropping the value if the ticket is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert_eq!(man.set_ticket(cat, 10), Some(1));
+    /// assert_eq!(man[cat], 10);
+    /// assert_eq!(man.set_ticket(tickets[1], 20), None);
+    /// assert_eq!(man.len(), 1);
+    /// ```
+    pub fn set_ticket(&mut self, ticket: Ticket, value: T) -> Option<T> {
+        let (_, old) = self.map.get_index_mut(ticket.to_index())?;
+        Some(core::mem::replace(old, value))
+    }
+
+    /// Returns a reference to the value associated with the given ticket,
+    /// or the given default if the ticket is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert_eq!(*man.get_ticket_or(cat, &0), 1);
+    /// assert_eq!(*man.get_ticket_or(tickets[1], &0), 0);
+    /// ```
+    pub fn get_ticket_or<'a>(&'a self, ticket: Ticket, default: &'a T) -> &'a T {
+        self.get_ticket_or_else(ticket, || default)
+    }
+
+    /// Returns a reference to the value associated with the given ticket,
+    /// or the reference returned by `default` if the ticket is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert_eq!(*man.get_ticket_or_else(cat, || &-1), 1);
+    /// assert_eq!(*man.get_ticket_or_else(tickets[1], || man.get_ticket(cat)), 1);
+    /// assert_eq!(*man.get_ticket_or_else(tickets[1], || &-1), -1);
+    /// ```
+    pub fn get_ticket_or_else<'a>(
+        &'a self,
+        ticket: Ticket,
+        default: impl FnOnce() -> &'a T,
+    ) -> &'a T {
+        match self.map.get_index(ticket.to_index()) {
+            Some((_, val)) => val,
+            None => default(),
+        }
+    }
+
+    /// Returns the ticket of the given identifier, if it exists
+    pub fn get_ticket_of(&self, id: &Identifier) -> Option<Ticket> {
+        self.map.get_index_of(id).and_then(Ticket::from_index)
+    }
+
+    /// Returns the ticket of a borrowed form of the identifier, if it exists
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket>::new();
+    /// let (cat, _) = man.insert("cat".to_string(), 1).unwrap();
+    ///
+    /// assert_eq!(man.get_ticket_of_q("cat"), Some(cat));
+    /// assert_eq!(man.get_ticket_of_q("dog"), None);
+    /// ```
+    pub fn get_ticket_of_q<Q>(&self, id: &Q) -> Option<Ticket>
+    where
+        Identifier: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_index_of(id).and_then(Ticket::from_index)
+    }
+
+    /// Returns the identifier of the given ticket, or `None` if the ticket is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    ///
+    /// let mut other = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = other.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert_eq!(man.id_of_ticket(cat), Some(&"cat"));
+    /// assert_eq!(man.id_of_ticket(tickets[1]), None);
+    /// ```
+    pub fn id_of_ticket(&self, ticket: Ticket) -> Option<&Identifier> {
+        self.map.get_index(ticket.to_index()).map(|(id, _)| id)
+    }
+
+    /// Returns the raw integer index of the given identifier, if it exists
+    ///
+    /// The index is the same as the one the identifier's ticket is constructed from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// let dog = man.get_ticket_of(&"dog").unwrap();
+    /// assert_eq!(man.raw_index_of(&"dog"), Some(dog.to_index()));
+    /// assert_eq!(man.raw_index_of(&"cow"), None);
+    /// ```
+    pub fn raw_index_of(&self, id: &Identifier) -> Option<usize> {
+        self.map.get_index_of(id)
+    }
+
+    /// Returns a reference to the value at the given raw integer index, if it's in bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (dog, _) = man.insert("dog", 2).unwrap();
+    ///
+    /// assert_eq!(man.value_at_raw(dog.to_index()), Some(man.get_ticket(dog)));
+    /// assert_eq!(man.value_at_raw(1), None);
+    /// ```
+    pub fn value_at_raw(&self, index: usize) -> Option<&T> {
+        self.map.get_index(index).map(|(_, val)| val)
+    }
+
+    /// Returns the ticket, a reference to the identifier,
+    /// and a reference to the value at the given raw integer index, if it's in bounds
+    ///
+    /// The raw index counterpart of [`try_get_ticket_full`](Self::try_get_ticket_full).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert_eq!(man.get_full_by_index(1), Some((tickets[1], &"dog", &2)));
+    /// assert_eq!(man.get_full_by_index(1), man.try_get_ticket_full(tickets[1]));
+    /// assert_eq!(man.get_full_by_index(2), None);
+    /// ```
+    pub fn get_full_by_index(&self, index: usize) -> Option<(Ticket, &Identifier, &T)> {
+        let (id, val) = self.map.get_index(index)?;
+        Some((Ticket::from_index(index)?, id, val))
+    }
+
+    /// Returns the global index of the ticket, when this registry's entries start at index `base`
+    /// of a larger address space
+    ///
+    /// Returns `None` if the ticket is out of bounds, or if the global index would overflow.
+    /// The inverse of [`local_ticket`](Self::local_ticket).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut first = RegistryManager::<i32, Ticket, &str>::new();
+    /// first.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// let mut second = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = second.insert_many([("cow", 3), ("pig", 4), ("hen", 5)]).unwrap();
+    ///
+    /// let base = first.len();
+    /// let global = second.rebase_ticket(tickets[1], base).unwrap();
+    /// assert_eq!(global, 3);
+    /// assert_eq!(second.local_ticket(global, base), Some(tickets[1]));
+    ///
+    /// assert_eq!(first.rebase_ticket(tickets[2], 0), None);
+    /// assert_eq!(second.local_ticket(1, base), None);
+    /// assert_eq!(second.local_ticket(5, base), None);
+    /// ```
+    pub fn rebase_ticket(&self, ticket: Ticket, base: usize) -> Option<usize> {
+        let index = ticket.to_index();
+        if index >= self.len() {
+            return None;
+        }
+        base.checked_add(index)
+    }
+
+    /// Returns the ticket of the given global index, when this registry's entries start at index `base`
+    /// of a larger address space
+    ///
+    /// Returns `None` if the index doesn't belong to this registry.
+    /// The inverse of [`rebase_ticket`](Self::rebase_ticket).
+    pub fn local_ticket(&self, global: usize, base: usize) -> Option<Ticket> {
+        let index = global.checked_sub(base)?;
+        if index >= self.len() {
+            return None;
+        }
+        Ticket::from_index(index)
+    }
+
+    /// Returns whether the given tickets point to the same index
+    ///
+    /// This works even if the ticket type doesn't implement [`PartialEq`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// #[derive(Debug, Clone, Copy, RegistryTicket)]
+    /// struct Ticket(u16);
+    ///
+    /// type Registry = RegistryManager<i32, Ticket, &'static str>;
+    ///
+    /// let mut man = Registry::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    /// let (dog, _) = man.insert("dog", 2).unwrap();
+    ///
+    /// assert!(Registry::tickets_equal(cat, man.get_ticket_of(&"cat").unwrap()));
+    /// assert!(!Registry::tickets_equal(cat, dog));
+    /// ```
+    pub fn tickets_equal(a: Ticket, b: Ticket) -> bool {
+        a.to_index() == b.to_index()
+    }
+
+    /// Binary searches the identifiers with a comparator function
+    ///
+    /// Returns the ticket of a matching entry, or the index where an entry with a matching identifier
+    /// could be inserted while keeping the order.
+    ///
+    /// The result is only meaningful if the entries are sorted by their identifiers,
+    /// in the order the comparator expects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("cat", 1), ("cow", 2), ("dog", 3)]).unwrap();
+    ///
+    /// assert_eq!(man.binary_search_by(|id| id.cmp(&&"cow")), Ok(tickets[1]));
+    /// assert_eq!(man.binary_search_by(|id| id.cmp(&&"cod")), Err(1));
+    /// assert_eq!(man.binary_search_by(|id| id.cmp(&&"pig")), Err(3));
+    /// ```
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<Ticket, usize>
+    where
+        F: FnMut(&Identifier) -> Ordering,
+    {
+        self.map
+            .binary_search_by(|id, _| f(id))
+            .map(|idx| Ticket::from_index(idx).expect("every stored entry was given a ticket"))
+    }
+
+    /// Returns references to all the values in a vector, where each value's position is its ticket's index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    ///
+    /// let values = man.to_value_vec();
+    /// for ticket in tickets {
+    ///     assert_eq!(values[ticket.to_index()], man.get_ticket(ticket));
+    /// }
+    /// ```
+    pub fn to_value_vec(&self) -> Vec<&T> {
+        self.map.values().collect()
+    }
+
+    /// Converts the registry into a vector of identifier and value pairs, in ticket order
+    ///
+    /// The index of each pair is the index of its ticket.
+    pub fn into_vec(self) -> Vec<(Identifier, T)> {
+        self.map.into_iter().collect()
+    }
+
+    /// Converts the registry into a vector of its values, where each value is at its ticket's index
+    ///
+    /// Since the tickets are always dense, every index of the vector is occupied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    /// let values: Vec<i32> = tickets.iter().map(|&ticket| man[ticket]).collect();
+    ///
+    /// let dense = man.into_dense_vec();
+    /// for (ticket, value) in tickets.into_iter().zip(values) {
+    ///     assert_eq!(dense[ticket.to_index()], value);
+    /// }
+    /// ```
+    pub fn into_dense_vec(self) -> Vec<T> {
+        self.map.into_values().collect()
+    }
+
+    /// Converts the registry into an iterator over its values, in ticket order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<String, Ticket, &str>::new();
+    /// man.insert("cat", "meow".to_string()).unwrap();
+    /// man.insert("dog", "woof".to_string()).unwrap();
+    ///
+    /// let values: Vec<String> = man.into_values().collect();
+    /// assert_eq!(values, ["meow", "woof"]);
+    /// ```
+    pub fn into_values(self) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator {
+        self.map.into_values()
+    }
+
+    /// Creates a registry from a vector of identifier and value pairs, keeping their order
+    ///
+    /// If an identifier appears more than once, its later value overwrites the earlier one,
+    /// but it keeps the ticket of its first appearance, just like with [`insert`](Self::insert).
+    ///
+    /// Returns [`RegistryFull`] if there are more unique identifiers than there are tickets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::{error::RegistryFull, *};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u8);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    ///
+    /// let vec = man.clone().into_vec();
+    /// assert_eq!(vec, [("cat", 1), ("dog", 2), ("cow", 3)]);
+    ///
+    /// let back = RegistryManager::<i32, Ticket, &str>::try_from_vec(vec).unwrap();
+    /// assert_eq!(back, man);
+    /// for (ticket, id) in tickets.into_iter().zip(["cat", "dog", "cow"]) {
+    ///     assert_eq!(back.get_ticket_of(&id), Some(ticket));
+    /// }
+    ///
+    /// let dup = RegistryManager::<i32, Ticket, &str>::try_from_vec(vec![("cat", 1), ("dog", 2), ("cat", 3)]);
+    /// assert_eq!(dup.unwrap().into_vec(), [("cat", 3), ("dog", 2)]);
+    ///
+    /// let big = (0..300).map(|i| (i, i)).collect();
+    /// assert_eq!(RegistryManager::<i32, Ticket, i32>::try_from_vec(big), Err(RegistryFull));
+    /// ```
+    pub fn try_from_vec(vec: Vec<(Identifier, T)>) -> Result<Self, RegistryFull>
+    where
+        S: Default,
+    {
+        let mut man = Self::with_hasher(S::default());
+        man.insert_many(vec)?;
+        Ok(man)
+    }
+
+    /// Checks that the ticket type round-trips every index of the registry
+    ///
+    /// Returns false if for any index `i < self.len()`,
+    /// `Ticket::from_index(i).map(|t| t.to_index())` isn't `Some(i)`,
+    /// which means that the [`RegistryTicket`] implementation is broken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager::*;
+    /// #[derive(Debug, Clone, Copy)]
+    /// struct BrokenTicket(u16);
+    ///
+    /// impl RegistryTicket for BrokenTicket {
+    ///     fn from_index(index: usize) -> Option<Self> {
+    ///         index.try_into().ok().map(Self)
+    ///     }
+    ///     fn to_index(&self) -> usize {
+    ///         self.0 as usize + 1
+    ///     }
+    /// }
+    ///
+    /// let mut man = RegistryManager::<i32, BrokenTicket, &str>::new();
+    /// assert!(man.debug_check());
+    ///
+    /// man.insert("cat", 1).unwrap();
+    /// assert!(!man.debug_check());
+    /// ```
+    pub fn debug_check(&self) -> bool {
+        (0..self.len()).all(|i| Ticket::from_index(i).map(|t| t.to_index()) == Some(i))
+    }
+
+    /// Returns a human readable listing of the entries, one `#<index> <identifier> = <value>` line per entry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<&str, Ticket, &str>::new();
+    /// man.insert_many([("cat", "meow"), ("dog", "woof")]).unwrap();
+    ///
+    /// assert_eq!(man.dump(), "#0 cat = meow\n#1 dog = woof\n");
+    /// ```
+    pub fn dump(&self) -> String
+    where
+        Identifier: Display,
+        T: Display,
+    {
+        let mut out = String::new();
+        for (idx, (id, val)) in self.map.iter().enumerate() {
+            // Writing to a string can't fail
+            let _ = writeln!(out, "#{idx} {id} = {val}");
+        }
+        out
+    }
+
+    /// Returns a deterministic fingerprint of the identifiers and values, in insertion order
+    ///
+    /// Unlike hashing with a randomly seeded hasher, the result is reproducible
+    /// for registries built with the same insertion sequence, also across runs and targets.
+    /// It uses a fixed 64-bit FNV-1a hash, writing integers as little endian
+    /// and `usize`/`isize` as 64-bit, so it only depends on the [`Hash`] implementations
+    /// of the identifier and value types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let build = || {
+    ///     let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    ///     man.insert("cat", 1).unwrap();
+    ///     man.insert("dog", 2).unwrap();
+    ///     man
+    /// };
+    ///
+    /// assert_eq!(build().fingerprint(), build().fingerprint());
+    ///
+    /// // The same on every run and target
+    /// assert_eq!(build().fingerprint(), 2710932298428765294);
+    /// ```
+    pub fn fingerprint(&self) -> u64
+    where
+        T: Hash,
+        Identifier: Hash,
+    {
+        let mut hasher = FingerprintHasher::new();
+        (self.map.len() as u64).hash(&mut hasher);
+        for (id, val) in &self.map {
+            id.hash(&mut hasher);
+            val.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Returns a referencing iterator over the values
+    ///
+    /// The iterator's item-type is `(Ticket, &'a Identifier, &'a T)`.
+    pub fn iter(&self) -> Iter<'_, T, Ticket, Identifier> {
+        self.assert_tickets_fit();
+        Iter {
+            iter: self.map.iter().enumerate(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a mutable iterator over the values
+    ///
+    /// The iterator's item-type is `(Ticket, &'a Identifier, &mut 'a T)`.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, Ticket, Identifier> {
+        self.assert_tickets_fit();
+        IterMut {
+            iter: self.map.iter_mut().enumerate(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a referencing iterator over the values, yielding raw indices instead of tickets
+    ///
+    /// The iterator's item-type is `(usize, &'a Identifier, &'a T)`.
+    /// This skips constructing a ticket for every item,
+    /// and the index can be turned into one with [`RegistryTicket::from_index`] when needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    ///
+    /// for ((idx, id, _), (ticket, ticket_id, _)) in man.iter_values_with_index().zip(man.iter()) {
+    ///     assert_eq!(idx, ticket.to_index());
+    ///     assert_eq!(id, ticket_id);
+    /// }
+    /// ```
+    pub fn iter_values_with_index(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (usize, &Identifier, &T)> + ExactSizeIterator {
+        self.map
+            .iter()
+            .enumerate()
+            .map(|(idx, (id, val))| (idx, id, val))
+    }
+
+    /// Returns a mutable iterator over the values, yielding both the tickets and their raw indices
+    ///
+    /// The iterator's item-type is `(Ticket, usize, &'a Identifier, &'a mut T)`.
+    /// The index is the same as `ticket.to_index()`, for code that needs both without the extra call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<usize, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    ///
+    /// for (ticket, idx, _, value) in man.iter_mut_indexed() {
+    ///     assert_eq!(ticket.to_index(), idx);
+    ///     *value += idx * 10;
+    /// }
+    /// assert_eq!(man.get_id(&"cow"), Some(&23));
+    /// ```
+    pub fn iter_mut_indexed(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (Ticket, usize, &Identifier, &mut T)> + ExactSizeIterator
+    {
+        self.assert_tickets_fit();
+        self.map.iter_mut().enumerate().map(|(idx, (id, val))| {
+            let ticket =
+                Ticket::from_index(idx).expect("iterator indices were checked on creation");
+            (ticket, idx, id, val)
+        })
+    }
+
+    /// Returns an iterator over the tickets of the registry, paired with their raw indices
+    ///
+    /// Useful for checking that a custom [`RegistryTicket`] implementation round-trips correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    ///
+    /// assert_eq!(man.ticket_index_pairs().count(), 3);
+    /// for (ticket, index) in man.ticket_index_pairs() {
+    ///     assert_eq!(ticket.to_index(), index);
+    /// }
+    /// ```
+    pub fn ticket_index_pairs(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (Ticket, usize)> + ExactSizeIterator {
+        self.assert_tickets_fit();
+        (0..self.len()).map(|idx| {
+            let ticket =
+                Ticket::from_index(idx).expect("iterator indices were checked on creation");
+            (ticket, idx)
+        })
+    }
+
+    /// Returns an iterator over the entries sorted by their identifiers, without reordering the registry
+    ///
+    /// The tickets are the entries' usual tickets. Collects references to all of the entries first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("dog", 2), ("cow", 3), ("cat", 1), ("pig", 4)]).unwrap();
+    ///
+    /// let ids: Vec<_> = man.sorted_iter().map(|(_, id, _)| *id).collect();
+    /// assert_eq!(ids, ["cat", "cow", "dog", "pig"]);
+    ///
+    /// for (ticket, id, value) in man.sorted_iter() {
+    ///     assert_eq!(man.get_ticket_full(ticket), (id, value));
+    /// }
+    /// assert_eq!(man.iter().next().map(|(_, id, _)| *id), Some("dog"));
+    /// ```
+    pub fn sorted_iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (Ticket, &Identifier, &T)> + ExactSizeIterator
+    where
+        Identifier: Ord,
+    {
+        let mut entries: Vec<_> = self.iter().collect();
+        entries.sort_unstable_by_key(|(_, id, _)| *id);
+        entries.into_iter()
+    }
+
+    /// Calls the given function with the ticket, identifier, and a mutable reference to the value of every entry
+    ///
+    /// Entries can't be removed, only mutated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<String, Ticket, &str>::new();
+    /// man.insert_many([("cat", "meow".to_string()), ("dog", "woof".to_string())]).unwrap();
+    ///
+    /// man.update_all(|ticket, _id, value| value.push_str(&ticket.to_index().to_string()));
+    ///
+    /// assert_eq!(man.get_id(&"cat").unwrap(), "meow0");
+    /// assert_eq!(man.get_id(&"dog").unwrap(), "woof1");
+    /// ```
+    pub fn update_all<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Ticket, &Identifier, &mut T),
+    {
+        for (ticket, id, val) in self.iter_mut() {
+            f(ticket, id, val);
+        }
+    }
+
+    /// Returns a parallel iterator over mutable references to the values, available with the `rayon` feature
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// use rayon::prelude::*;
+    ///
+    /// let mut man = RegistryManager::<i64, Ticket, i64>::new();
+    /// man.insert_many((0..1000).map(|i| (i, i))).unwrap();
+    ///
+    /// let mut sequential = man.clone();
+    /// sequential.update_all(|_, _, value| *value *= 2);
+    ///
+    /// man.par_values_mut().for_each(|value| *value *= 2);
+    /// assert_eq!(man.to_value_vec(), sequential.to_value_vec());
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_values_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut T>
+    where
+        T: Send,
+        Identifier: Send,
+    {
+        self.map.par_values_mut()
+    }
+
+    /// Returns an iterator over chunks of `size` entries, in insertion order
+    ///
+    /// The tickets of the entries are absolute, not relative to the chunk.
+    /// The last chunk can be shorter than `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("a", 1), ("b", 2), ("c", 3), ("d", 4), ("e", 5)]).unwrap();
+    ///
+    /// let chunks: Vec<_> = man.chunks(2).collect();
+    /// assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), [2, 2, 1]);
+    /// assert_eq!(chunks[1][0], (tickets[2], &"c", &3));
+    /// assert_eq!(chunks[2][0], (tickets[4], &"e", &5));
+    /// ```
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = Vec<(Ticket, &Identifier, &T)>> {
+        assert!(size != 0, "chunk size must be non-zero");
+        let mut iter = self.iter();
+        core::iter::from_fn(move || {
+            let chunk: Vec<_> = iter.by_ref().take(size).collect();
+            (!chunk.is_empty()).then_some(chunk)
+        })
+    }
+
+    /// Returns the ticket of the first entry, or `None` if the registry is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// assert_eq!(man.first_ticket(), None);
+    /// assert_eq!(man.last_ticket(), None);
+    ///
+    /// let tickets = man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    /// assert_eq!(man.first_ticket(), Some(tickets[0]));
+    /// assert_eq!(man.last_ticket(), Some(tickets[2]));
+    /// ```
+    pub fn first_ticket(&self) -> Option<Ticket> {
+        if self.is_empty() {
+            return None;
+        }
+        Ticket::from_index(0)
+    }
+
+    /// Returns the ticket of the last entry, or `None` if the registry is empty
+    ///
+    /// See [`first_ticket`](Self::first_ticket) for an example.
+    pub fn last_ticket(&self) -> Option<Ticket> {
+        Ticket::from_index(self.len().checked_sub(1)?)
+    }
+
+    /// Returns a referencing iterator over the entries between the given tickets
+    ///
+    /// Unlike indexing with a range, the range is clamped to the registry's bounds instead of panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man
+    ///     .insert_many([("cat", 1), ("dog", 2), ("cow", 3), ("pig", 4), ("hen", 5)])
+    ///     .unwrap();
+    ///
+    /// let window: Vec<_> = man.tickets_in(tickets[1]..tickets[3]).collect();
+    /// assert_eq!(window, [(tickets[1], &"dog", &2), (tickets[2], &"cow", &3)]);
+    ///
+    /// let mut big = RegistryManager::<i32, Ticket, i32>::new();
+    /// let far = big.insert_many((0..10).map(|i| (i, i))).unwrap();
+    /// assert_eq!(man.tickets_in(tickets[3]..far[9]).count(), 2);
+    /// assert_eq!(man.tickets_in(far[8]..far[9]).count(), 0);
+    /// ```
+    pub fn tickets_in(
+        &self,
+        range: Range<Ticket>,
+    ) -> impl DoubleEndedIterator<Item = (Ticket, &Identifier, &T)> + ExactSizeIterator {
+        let end = range.end.to_index().min(self.len());
+        let start = range.start.to_index().min(end);
+        self.iter().skip(start).take(end - start)
+    }
+
+    /// Divides the entries into two slices at the given ticket
+    ///
+    /// The first slice contains the entries before the ticket, and the second one the rest.
+    /// The absolute ticket index of an entry in the second slice is its index in the slice
+    /// plus the index of the given ticket.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ticket's index is greater than the registry's length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("cat", 1), ("dog", 2), ("cow", 3), ("pig", 4)]).unwrap();
+    ///
+    /// let (left, right) = man.split_at(tickets[2]);
+    /// assert_eq!(left.iter().collect::<Vec<_>>(), [(&"cat", &1), (&"dog", &2)]);
+    /// assert_eq!(right.iter().collect::<Vec<_>>(), [(&"cow", &3), (&"pig", &4)]);
+    ///
+    /// let split = tickets[2].to_index();
+    /// for (i, (id, _)) in right.iter().enumerate() {
+    ///     assert_eq!(man.get_ticket_of(id).unwrap().to_index(), split + i);
+    /// }
+    /// ```
+    pub fn split_at(&self, ticket: Ticket) -> (&Slice<Identifier, T>, &Slice<Identifier, T>) {
+        let index = ticket.to_index();
+        if index > self.len() {
+            index_out_of_bounds(index, self.len());
+        }
+        self.map.as_slice().split_at(index)
     }
 
-    /// Returns the ticket of the given identifier, if it exists
-    pub fn get_ticket_of(&self, id: &Identifier) -> Option<Ticket> {
-        self.map.get_index_of(id).and_then(Ticket::from_index)
+    /// Groups the entries into buckets by the key returned by `key`, keeping their tickets
+    ///
+    /// The entries in each bucket are in ticket order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// struct Animal {
+    ///     category: &'static str,
+    ///     legs: u32,
+    /// }
+    ///
+    /// let mut man = RegistryManager::<Animal, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", Animal { category: "mammal", legs: 4 }).unwrap();
+    /// let (crow, _) = man.insert("crow", Animal { category: "bird", legs: 2 }).unwrap();
+    /// let (dog, _) = man.insert("dog", Animal { category: "mammal", legs: 4 }).unwrap();
+    ///
+    /// let groups = man.group_by(|animal| animal.category);
+    /// assert_eq!(groups.len(), 2);
+    ///
+    /// let mammals: Vec<_> = groups["mammal"].iter().map(|&(ticket, id, _)| (ticket, *id)).collect();
+    /// assert_eq!(mammals, [(cat, "cat"), (dog, "dog")]);
+    /// assert_eq!(groups["bird"][0].0, crow);
+    ///
+    /// for (ticket, _, animal) in groups.values().flatten() {
+    ///     assert_eq!(man[*ticket].legs, animal.legs);
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn group_by<K, F>(&self, mut key: F) -> HashMap<K, Vec<(Ticket, &Identifier, &T)>>
+    where
+        K: Hash + Eq,
+        F: FnMut(&T) -> K,
+    {
+        let mut groups: HashMap<K, Vec<_>> = HashMap::new();
+        for (ticket, id, value) in self.iter() {
+            groups
+                .entry(key(value))
+                .or_default()
+                .push((ticket, id, value));
+        }
+        groups
     }
 
-    /// Returns a referencing iterator over the values
+    /// Returns a map from each identifier to its ticket, cloning the identifiers
     ///
-    /// The iterator's item-type is `(Ticket, &'a Identifier, &'a T)`.
-    pub fn iter(&self) -> Iter<T, Ticket, Identifier> {
-        Iter {
-            iter: self.map.iter().enumerate(),
-            _phantom: PhantomData,
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    ///
+    /// let tickets = man.ticket_map();
+    /// assert_eq!(tickets.len(), 3);
+    /// for (id, ticket) in &tickets {
+    ///     assert_eq!(man.get_ticket_of(id), Some(*ticket));
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn ticket_map(&self) -> HashMap<Identifier, Ticket>
+    where
+        Identifier: Clone,
+    {
+        self.iter()
+            .map(|(ticket, id, _)| (id.clone(), ticket))
+            .collect()
+    }
+
+    /// Returns the number of values satisfying the given predicate
+    ///
+    /// This is a single pass over the values, without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<&str, Ticket, &str>::new();
+    /// man.insert_many([("cat", "meow"), ("dog", "woof"), ("cow", "moo")]).unwrap();
+    ///
+    /// assert_eq!(man.count_by(|sound| sound.len() == 4), 2);
+    /// assert_eq!(man.count_by(|sound| sound.is_empty()), 0);
+    /// ```
+    pub fn count_by<F>(&self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.map.values().filter(|value| pred(value)).count()
+    }
+
+    /// Returns the differences between this and the other registry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut old = RegistryManager::<i32, Ticket, &str>::new();
+    /// old.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    ///
+    /// let mut new = RegistryManager::<i32, Ticket, &str>::new();
+    /// new.insert_many([("pig", 4), ("dog", 20), ("cat", 1)]).unwrap();
+    ///
+    /// let diff = old.diff(&new);
+    /// let cow = old.get_ticket_of(&"cow").unwrap();
+    /// let pig = new.get_ticket_of(&"pig").unwrap();
+    /// assert_eq!(diff.only_in_self().collect::<Vec<_>>(), [(cow, &"cow", &3)]);
+    /// assert_eq!(diff.only_in_other().collect::<Vec<_>>(), [(pig, &"pig", &4)]);
+    ///
+    /// let dog_old = old.get_ticket_of(&"dog").unwrap();
+    /// let dog_new = new.get_ticket_of(&"dog").unwrap();
+    /// let changed: Vec<_> = diff.changed().collect();
+    /// assert_eq!(changed, [(&"dog", (dog_old, &2), (dog_new, &20))]);
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a Self) -> RegistryDiff<'a, T, Ticket, Identifier, S>
+    where
+        T: PartialEq,
+    {
+        RegistryDiff {
+            left: self,
+            right: other,
         }
     }
 
-    /// Returns a mutable iterator over the values
+    /// Calls the given function with a branded version of this registry, returning its result
     ///
-    /// The iterator's item-type is `(Ticket, &'a Identifier, &mut 'a T)`.
-    pub fn iter_mut(&mut self) -> IterMut<T, Ticket, Identifier> {
-        IterMut {
-            iter: self.map.iter_mut().enumerate(),
-            _phantom: PhantomData,
+    /// The tickets of a [`BrandedRegistry`] carry a unique lifetime,
+    /// so using them with another branded registry is a compile error.
+    /// The registry can be returned from the function with [`BrandedRegistry::into_inner`].
+    pub fn with_branded<R>(
+        self,
+        f: impl for<'brand> FnOnce(BrandedRegistry<'brand, T, Ticket, Identifier, S>) -> R,
+    ) -> R {
+        f(BrandedRegistry {
+            inner: self,
+            _brand: PhantomData,
+        })
+    }
+
+    /// Returns an iterator over the entries zipped with a slice of auxiliary per-entry data
+    ///
+    /// Returns `None` if the slice's length doesn't match the registry's length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// let zipped: Vec<_> = man.zip_aux(&['c', 'd']).unwrap().collect();
+    /// assert_eq!(zipped, [(tickets[0], &"cat", &1, &'c'), (tickets[1], &"dog", &2, &'d')]);
+    ///
+    /// assert!(man.zip_aux(&['c']).is_none());
+    /// ```
+    pub fn zip_aux<'a, A>(
+        &'a self,
+        aux: &'a [A],
+    ) -> Option<impl Iterator<Item = (Ticket, &'a Identifier, &'a T, &'a A)>> {
+        (aux.len() == self.len()).then(|| {
+            self.iter()
+                .zip(aux)
+                .map(|((ticket, id, val), a)| (ticket, id, val, a))
+        })
+    }
+
+    /// Returns a new registry containing only the entries for which `keep` returns true,
+    /// along with a remapping of the tickets
+    ///
+    /// The remapping is indexed by the old ticket indices,
+    /// and contains the entry's new ticket, or `None` if the entry was dropped.
+    /// The new registry uses a clone of this registry's hasher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let old = man.insert_many([("cat", 1), ("dog", -2), ("cow", 3)]).unwrap();
+    ///
+    /// let (compacted, remap) = man.compact_by(|_, value| *value > 0);
+    /// assert_eq!(compacted.len(), 2);
+    /// assert_eq!(remap[old[1].to_index()], None);
+    ///
+    /// let cow = remap[old[2].to_index()].unwrap();
+    /// assert_eq!(compacted.get_ticket_full(cow), (&"cow", &3));
+    /// assert_eq!(remap[old[0].to_index()], compacted.get_ticket_of(&"cat"));
+    /// ```
+    pub fn compact_by<F>(&self, mut keep: F) -> (Self, Vec<Option<Ticket>>)
+    where
+        F: FnMut(&Identifier, &T) -> bool,
+        T: Clone,
+        Identifier: Clone,
+        S: Clone,
+    {
+        let mut compacted = Self::with_hasher(self.map.hasher().clone());
+        let remap = self
+            .map
+            .iter()
+            .map(|(id, val)| {
+                keep(id, val).then(|| compacted.insert(id.clone(), val.clone()).unwrap().0)
+            })
+            .collect();
+        (compacted, remap)
+    }
+
+    /// Returns a new registry with the values `f` maps to `Some`, keeping their order
+    ///
+    /// The entries of the new registry are densely packed, so their tickets differ from the old ones
+    /// and are only valid with the new registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", -2), ("cow", 3)]).unwrap();
+    ///
+    /// let strings = man.filter_map_into(|_, value| (*value > 0).then(|| value.to_string()));
+    /// assert_eq!(strings.len(), 2);
+    ///
+    /// let entries: Vec<_> = strings.iter().map(|(_, id, value)| (*id, value.as_str())).collect();
+    /// assert_eq!(entries, [("cat", "1"), ("cow", "3")]);
+    /// assert_ne!(strings.get_ticket_of(&"cow"), man.get_ticket_of(&"cow"));
+    /// ```
+    pub fn filter_map_into<U, F>(&self, mut f: F) -> RegistryManager<U, Ticket, Identifier, S>
+    where
+        F: FnMut(&Identifier, &T) -> Option<U>,
+        Identifier: Clone,
+        S: Clone,
+    {
+        let mut mapped = RegistryManager::with_hasher(self.map.hasher().clone());
+        for (id, val) in &self.map {
+            if let Some(new_val) = f(id, val) {
+                mapped.insert(id.clone(), new_val).unwrap();
+            }
         }
+        mapped
+    }
+
+    /// Sorts the entries with the comparison function `cmp`, using an unstable sort,
+    /// and returns a remapping of the tickets
+    ///
+    /// The remapping is indexed by the old ticket indices, and contains the entry's new ticket.
+    /// This invalidates all previously handed out tickets, so they have to be mapped through it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, i32>::new();
+    /// let old = man.insert_many((0..100).map(|i| (i, (i * 37) % 101))).unwrap();
+    ///
+    /// let mut stable = man.clone().into_vec();
+    /// stable.sort_by(|(_, a), (_, b)| a.cmp(b));
+    ///
+    /// let remap = man.sort_unstable_by(|_, a, _, b| a.cmp(b));
+    /// assert_eq!(man.clone().into_vec(), stable);
+    ///
+    /// for (i, ticket) in old.into_iter().enumerate() {
+    ///     let new = remap[ticket.to_index()];
+    ///     assert_eq!(man.get_ticket_full(new), (&(i as i32), &((i as i32 * 37) % 101)));
+    /// }
+    /// ```
+    pub fn sort_unstable_by<F>(&mut self, mut cmp: F) -> Vec<Ticket>
+    where
+        F: FnMut(&Identifier, &T, &Identifier, &T) -> Ordering,
+    {
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        order.sort_unstable_by(|&a, &b| {
+            let (a_id, a_val) = self.expect_index(a);
+            let (b_id, b_val) = self.expect_index(b);
+            cmp(a_id, a_val, b_id, b_val)
+        });
+
+        // Move the entries into their sorted positions in place, tracking where each old entry is
+        let mut position_of: Vec<usize> = (0..order.len()).collect();
+        let mut entry_at = position_of.clone();
+        let mut new_index = position_of.clone();
+        for (new, &old) in order.iter().enumerate() {
+            let current = position_of[old];
+            if current != new {
+                self.map.swap_indices(new, current);
+                let displaced = entry_at[new];
+                entry_at[current] = displaced;
+                position_of[displaced] = current;
+                entry_at[new] = old;
+                position_of[old] = new;
+            }
+            new_index[old] = new;
+        }
+
+        new_index
+            .into_iter()
+            .map(|idx| Ticket::from_index(idx).expect("every stored entry was given a ticket"))
+            .collect()
+    }
+
+    /// Swaps the positions of the entries of the two tickets, so that their identifiers trade tickets
+    ///
+    /// This invalidates any held copies of the two tickets:
+    /// afterwards `a` points to the entry `b` used to point to, and the other way around.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either of the tickets is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = man.insert("cat", 1).unwrap();
+    /// let (dog, _) = man.insert("dog", 2).unwrap();
+    /// let (cow, _) = man.insert("cow", 3).unwrap();
+    ///
+    /// man.swap_tickets(cat, cow);
+    /// assert_eq!(man.get_ticket_of(&"cat"), Some(cow));
+    /// assert_eq!(man.get_ticket_of(&"cow"), Some(cat));
+    /// assert_eq!(man.get_ticket_full(cow), (&"cat", &1));
+    /// assert_eq!(man.get_ticket_full(cat), (&"cow", &3));
+    /// assert_eq!(man.get_ticket_full(dog), (&"dog", &2));
+    /// ```
+    pub fn swap_tickets(&mut self, a: Ticket, b: Ticket) {
+        let (a, b) = (a.to_index(), b.to_index());
+        self.expect_index(a);
+        self.expect_index(b);
+        self.map.swap_indices(a, b);
+    }
+
+    /// Wraps the registry into one which caches the tickets of up to `cache_size` recently resolved identifiers
+    ///
+    /// See [`CachingRegistry`].
+    pub fn with_ticket_cache(self, cache_size: usize) -> CachingRegistry<T, Ticket, Identifier, S> {
+        CachingRegistry {
+            inner: self,
+            cache: Vec::with_capacity(cache_size),
+            cache_size,
+        }
+    }
+
+    /// Wraps the registry into one which stores a metadata value for each entry,
+    /// starting from the default value
+    ///
+    /// See [`RegistryManagerWithMeta`].
+    pub fn with_sidecar<M: Default>(self) -> RegistryManagerWithMeta<T, Ticket, Identifier, S, M> {
+        let mut meta = Vec::with_capacity(self.len());
+        meta.resize_with(self.len(), M::default);
+        RegistryManagerWithMeta { inner: self, meta }
+    }
+
+    /// Turns the registry into an immutable view, which is cheap to clone and share between threads
+    ///
+    /// See [`FrozenRegistry`].
+    pub fn freeze(self) -> FrozenRegistry<T, Ticket, Identifier, S> {
+        FrozenRegistry {
+            inner: Arc::new(self),
+        }
+    }
+}
+
+impl<T, Ticket, S> RegistryManager<T, Ticket, String, S>
+where
+    Ticket: RegistryTicket,
+    S: BuildHasher,
+{
+    /// Returns an iterator over the entries whose identifier starts with the given prefix,
+    /// in insertion order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket>::new();
+    /// let (cat, _) = man.insert("cat".to_string(), 1).unwrap();
+    /// let (car, _) = man.insert("car".to_string(), 2).unwrap();
+    /// man.insert("dog".to_string(), 3).unwrap();
+    ///
+    /// let found: Vec<_> = man.with_prefix("ca").map(|(ticket, _, _)| ticket).collect();
+    /// assert_eq!(found, [cat, car]);
+    /// ```
+    pub fn with_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = (Ticket, &'a String, &'a T)> {
+        self.iter().filter(move |(_, id, _)| id.starts_with(prefix))
+    }
+}
+
+/// The fixed FNV-1a hasher used by [`RegistryManager::fingerprint`]
+///
+/// Integers are written as little endian with a fixed width, so the result doesn't depend on the target.
+struct FingerprintHasher(u64);
+
+impl FingerprintHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FingerprintHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
     }
 }
 
-impl<T, Ticket, Identifier> Default for RegistryManager<T, Ticket, Identifier>
+#[cold]
+fn index_out_of_bounds(index: usize, len: usize) -> ! {
+    panic!("ticket index {index} out of bounds (len {len})")
+}
+
+#[cold]
+fn raw_index_out_of_bounds(index: usize, len: usize) -> ! {
+    panic!("index {index} out of bounds (len {len})")
+}
+
+impl<T, Ticket, Identifier, S> Default for RegistryManager<T, Ticket, Identifier, S>
 where
     Ticket: RegistryTicket,
     Identifier: Hash + Eq,
+    S: BuildHasher + Default,
 {
     fn default() -> Self {
-        Self::new()
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<T, Ticket, Identifier, S> Clone for RegistryManager<T, Ticket, Identifier, S>
+where
+    T: Clone,
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq + Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut map = self.map.clone();
+        if self.no_grow {
+            map.reserve(self.map.capacity().saturating_sub(map.len()));
+        }
+        Self {
+            map,
+            no_grow: self.no_grow,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Overwrites the registry with a clone of `source`, reusing the existing allocations where possible
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut source = RegistryManager::<i32, Ticket, &str>::new();
+    /// source.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// let mut snapshot = RegistryManager::<i32, Ticket, &str>::new();
+    /// for value in 0..3 {
+    ///     *source.get_id_mut(&"cat").unwrap() = value;
+    ///     snapshot.clone_from(&source);
+    ///     assert_eq!(snapshot, source);
+    ///     assert_eq!(snapshot.get_id(&"cat"), Some(&value));
+    /// }
+    /// ```
+    fn clone_from(&mut self, source: &Self) {
+        self.map.clone_from(&source.map);
+        self.no_grow = source.no_grow;
+        if self.no_grow {
+            self.map
+                .reserve(source.map.capacity().saturating_sub(self.map.len()));
+        }
+    }
+}
+
+impl<T, Ticket, Identifier, S1, S2> PartialEq<RegistryManager<T, Ticket, Identifier, S2>>
+    for RegistryManager<T, Ticket, Identifier, S1>
+where
+    T: PartialEq,
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S1: BuildHasher,
+    S2: BuildHasher,
+{
+    fn eq(&self, other: &RegistryManager<T, Ticket, Identifier, S2>) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T, Ticket, Identifier, S1, S2> PartialEq<IndexMap<Identifier, T, S2>>
+    for RegistryManager<T, Ticket, Identifier, S1>
+where
+    T: PartialEq,
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    /// Compares the entries of the registry with the map, in order
+    ///
+    /// Unlike comparing two registries or two maps, the order matters,
+    /// since it decides which ticket each entry would get.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # use indexmap::IndexMap;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// let same: IndexMap<_, _> = [("cat", 1), ("dog", 2)].into_iter().collect();
+    /// let reordered: IndexMap<_, _> = [("dog", 2), ("cat", 1)].into_iter().collect();
+    /// let changed: IndexMap<_, _> = [("cat", 1), ("dog", 3)].into_iter().collect();
+    ///
+    /// assert!(man == same);
+    /// assert!(man != reordered);
+    /// assert!(man != changed);
+    /// ```
+    fn eq(&self, other: &IndexMap<Identifier, T, S2>) -> bool {
+        self.map.len() == other.len() && self.map.iter().eq(other.iter())
     }
 }
 
-impl<T, Ticket, Identifier> Index<Ticket> for RegistryManager<T, Ticket, Identifier>
+impl<T, Ticket, Identifier, S> Eq for RegistryManager<T, Ticket, Identifier, S>
+where
+    T: Eq,
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+}
+
+impl<T, Ticket, Identifier, S> Index<Ticket> for RegistryManager<T, Ticket, Identifier, S>
 where
     Ticket: RegistryTicket,
     Identifier: Hash + Eq,
+    S: BuildHasher,
 {
     type Output = T;
     /// Returns a reference to the value associated by the ticket
@@ -321,10 +3776,11 @@ where
     }
 }
 
-impl<T, Ticket, Identifier> IndexMut<Ticket> for RegistryManager<T, Ticket, Identifier>
+impl<T, Ticket, Identifier, S> IndexMut<Ticket> for RegistryManager<T, Ticket, Identifier, S>
 where
     Ticket: RegistryTicket,
     Identifier: Hash + Eq,
+    S: BuildHasher,
 {
     /// Returns a mutable reference to the value associated by the ticket
     ///
@@ -333,3 +3789,229 @@ where
         self.get_ticket_mut(ticket)
     }
 }
+
+impl<T, Ticket, Identifier, S> Index<usize> for RegistryManager<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    type Output = T;
+    /// Returns a reference to the value at the given raw index
+    ///
+    /// # Panics
+    ///
+    /// Panics with the index and the registry's length if the index is out of bounds:
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert("cat", 1).unwrap();
+    ///
+    /// let panic = std::panic::catch_unwind(|| man[3usize]).unwrap_err();
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "index 3 out of bounds (len 1)");
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// assert_eq!(man[0usize], 1);
+    /// assert_eq!(man[1usize], 2);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert("cat", 1).unwrap();
+    ///
+    /// let _ = man[1usize];
+    /// ```
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.map.get_index(index) {
+            Some((_, val)) => val,
+            None => raw_index_out_of_bounds(index, self.len()),
+        }
+    }
+}
+
+impl<T, Ticket, Identifier, S> Index<Range<Ticket>> for RegistryManager<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    type Output = Slice<Identifier, T>;
+    /// Returns a slice of the entries between the given tickets
+    ///
+    /// There is no matching `Index<&Identifier>` implementation, since it would overlap with
+    /// `Index<Ticket>` when the ticket type is itself a reference to the identifier type.
+    /// Use [`get_id`](RegistryManager::get_id) to look values up by their identifiers instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends, or if it ends past the registry's length:
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let tickets = man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// let mut big = RegistryManager::<i32, Ticket, i32>::new();
+    /// let far = big.insert_many((0..5).map(|i| (i, i))).unwrap();
+    ///
+    /// let panic = std::panic::catch_unwind(|| &man[tickets[1]..tickets[0]]).unwrap_err();
+    /// assert_eq!(
+    ///     panic.downcast_ref::<String>().unwrap(),
+    ///     "ticket range starts at index 1 but ends at index 0"
+    /// );
+    ///
+    /// let panic = std::panic::catch_unwind(|| &man[tickets[0]..far[4]]).unwrap_err();
+    /// assert_eq!(panic.downcast_ref::<String>().unwrap(), "ticket index 4 out of bounds (len 2)");
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (_cat, _) = man.insert("cat", 1).unwrap();
+    /// let (dog, _) = man.insert("dog", 2).unwrap();
+    /// let (_cow, _) = man.insert("cow", 3).unwrap();
+    /// let (pig, _) = man.insert("pig", 4).unwrap();
+    ///
+    /// let slice = &man[dog..pig];
+    /// let entries: Vec<_> = slice.iter().collect();
+    /// assert_eq!(entries, [(&"dog", &2), (&"cow", &3)]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u16);
+    /// let mut small = RegistryManager::<i32, Ticket, &str>::new();
+    /// let (cat, _) = small.insert("cat", 1).unwrap();
+    ///
+    /// let mut big = RegistryManager::<i32, Ticket, &str>::new();
+    /// big.insert_many([("cat", 1), ("dog", 2), ("cow", 3)]).unwrap();
+    /// let cow = big.get_ticket_of(&"cow").unwrap();
+    ///
+    /// let _ = &small[cat..cow];
+    /// ```
+    fn index(&self, range: Range<Ticket>) -> &Self::Output {
+        let (start, end) = (range.start.to_index(), range.end.to_index());
+        if start > end {
+            panic!("ticket range starts at index {start} but ends at index {end}");
+        }
+        if end > self.len() {
+            index_out_of_bounds(end, self.len());
+        }
+        &self.map[start..end]
+    }
+}
+
+impl<T, Ticket, Identifier, S> TryFrom<IndexMap<Identifier, T, S>>
+    for RegistryManager<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    type Error = RegistryFull;
+    /// Wraps the given map into a registry manager, keeping its order
+    ///
+    /// Returns [`RegistryFull`] if the map has more values than there are tickets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::{error::RegistryFull, *};
+    /// # use indexmap::IndexMap;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u8);
+    /// let map: IndexMap<_, _> = [("cat", 1), ("dog", 2)].into_iter().collect();
+    /// let man = RegistryManager::<i32, Ticket, &str>::try_from(map).unwrap();
+    /// assert_eq!(man.get_id(&"dog"), Some(&2));
+    ///
+    /// let big: IndexMap<_, _> = (0..300).map(|i| (i, i)).collect();
+    /// let err = RegistryManager::<i32, Ticket, i32>::try_from(big);
+    /// assert_eq!(err, Err(RegistryFull));
+    /// ```
+    fn try_from(map: IndexMap<Identifier, T, S>) -> Result<Self, Self::Error> {
+        if !map.is_empty() && Ticket::from_index(map.len() - 1).is_none() {
+            return Err(RegistryFull);
+        }
+
+        Ok(Self {
+            map,
+            no_grow: false,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T, Ticket, Identifier, S> From<RegistryManager<T, Ticket, Identifier, S>>
+    for IndexMap<Identifier, T, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    /// Unwraps the registry manager into its internal map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use registry_ticket_manager_proc_macro::RegistryTicket;
+    /// # use registry_ticket_manager::*;
+    /// # use indexmap::IndexMap;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+    /// # struct Ticket(u8);
+    /// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+    /// man.insert_many([("cat", 1), ("dog", 2)]).unwrap();
+    ///
+    /// let map = IndexMap::from(man);
+    /// assert_eq!(map.get_index(1), Some((&"dog", &2)));
+    /// ```
+    fn from(man: RegistryManager<T, Ticket, Identifier, S>) -> Self {
+        man.map
+    }
+}
+
+impl<T, Ticket, Identifier, S> IntoIterator for RegistryManager<T, Ticket, Identifier, S>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    type Item = (Ticket, Identifier, T);
+    type IntoIter = IntoIter<T, Ticket, Identifier>;
+
+    /// Consumes the registry manager into an owning iterator over its entries, in ticket order
+    fn into_iter(self) -> Self::IntoIter {
+        self.assert_tickets_fit();
+        IntoIter {
+            iter: self.map.into_iter().enumerate(),
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use alloc::vec::Vec;
+use core::{
+    hash::{BuildHasher, Hash},
+    ops::Deref,
+};
+
+use crate::*;
+
+/// A [`RegistryManager`] with an additional metadata value for each entry, stored alongside by ticket index
+///
+/// Created by [`RegistryManager::with_sidecar`].
+/// Every inserted entry gets a default metadata value, which can be changed independently of the entry's value.
+///
+/// It dereferences to the registry manager for reading,
+/// and mutating is limited to methods which keep the metadata aligned with the entries.
+///
+/// # Examples
+///
+/// ```
+/// # use registry_ticket_manager_proc_macro::RegistryTicket;
+/// # use registry_ticket_manager::*;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+/// # struct Ticket(u16);
+/// let mut man = RegistryManager::<i32, Ticket, &str>::new();
+/// let (cat, _) = man.insert("cat", 1).unwrap();
+///
+/// let mut man = man.with_sidecar::<u32>();
+/// let (dog, _) = man.insert("dog", 2).unwrap();
+/// *man.meta_mut(dog) += 10;
+/// let (cow, _) = man.insert("cow", 3).unwrap();
+/// *man.meta_mut(cat) += 1;
+///
+/// // Overwriting a value keeps its metadata
+/// man.insert("dog", 20).unwrap();
+///
+/// assert_eq!([*man.meta(cat), *man.meta(dog), *man.meta(cow)], [1, 10, 0]);
+/// assert_eq!(man[dog], 20);
+///
+/// let (man, meta) = man.into_parts();
+/// assert_eq!(meta.len(), man.len());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RegistryManagerWithMeta<T, Ticket, Identifier, S, M>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    pub(crate) inner: RegistryManager<T, Ticket, Identifier, S>,
+    pub(crate) meta: Vec<M>,
+}
+
+impl<T, Ticket, Identifier, S, M> RegistryManagerWithMeta<T, Ticket, Identifier, S, M>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+    M: Default,
+{
+    /// Inserts the value to the registry with the given identifier
+    ///
+    /// A new entry gets a default metadata value, and an overwritten one keeps its metadata.
+    /// See [`RegistryManager::insert`].
+    pub fn insert(&mut self, id: Identifier, value: T) -> Option<(Ticket, Option<T>)> {
+        let (ticket, old) = self.inner.insert(id, value)?;
+        if old.is_none() {
+            self.meta.push(M::default());
+        }
+        Some((ticket, old))
+    }
+}
+
+impl<T, Ticket, Identifier, S, M> RegistryManagerWithMeta<T, Ticket, Identifier, S, M>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Returns a reference to the metadata associated with the given ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    pub fn meta(&self, ticket: Ticket) -> &M {
+        let index = ticket.to_index();
+        self.meta
+            .get(index)
+            .unwrap_or_else(|| index_out_of_bounds(index, self.meta.len()))
+    }
+
+    /// Returns a mutable reference to the metadata associated with the given ticket
+    ///
+    /// Assumes that the given ticket is valid.
+    pub fn meta_mut(&mut self, ticket: Ticket) -> &mut M {
+        let index = ticket.to_index();
+        let len = self.meta.len();
+        self.meta
+            .get_mut(index)
+            .unwrap_or_else(|| index_out_of_bounds(index, len))
+    }
+
+    /// Returns a mutable reference to the value associated with the given ticket
+    ///
+    /// See [`RegistryManager::get_ticket_mut`].
+    pub fn get_ticket_mut(&mut self, ticket: Ticket) -> &mut T {
+        self.inner.get_ticket_mut(ticket)
+    }
+
+    /// Returns the registry manager and the metadata, indexed by ticket index
+    pub fn into_parts(self) -> (RegistryManager<T, Ticket, Identifier, S>, Vec<M>) {
+        (self.inner, self.meta)
+    }
+}
+
+impl<T, Ticket, Identifier, S, M> Deref for RegistryManagerWithMeta<T, Ticket, Identifier, S, M>
+where
+    Ticket: RegistryTicket,
+    Identifier: Hash + Eq,
+{
+    type Target = RegistryManager<T, Ticket, Identifier, S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
@@ -0,0 +1,48 @@
+use alloc::string::String;
+
+/// A snapshot of a [`RegistryManager`](crate::RegistryManager)'s size and allocation
+///
+/// Created by [`RegistryManager::stats`](crate::RegistryManager::stats).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegistryStats {
+    /// The number of stored values
+    pub len: usize,
+    /// The number of values the registry can hold without reallocating
+    pub capacity: usize,
+    /// The number of values that can still be inserted without reallocating
+    pub remaining_capacity: usize,
+    /// The ratio of `len` to `capacity`, or `0.0` if nothing has been allocated
+    pub load_factor: f64,
+}
+
+/// The size of a value for memory accounting, as used by
+/// [`RegistryManager::total_value_size`](crate::RegistryManager::total_value_size)
+///
+/// What counts as the size is up to the implementation. The primitive types report their
+/// [`size_of`], and [`String`] the length of its contents in bytes.
+pub trait ValueSize {
+    /// Returns the size of this value
+    fn size(&self) -> usize;
+}
+
+macro_rules! impl_value_size_primitive {
+    ($($ty:ty),*) => {
+        $(
+            impl ValueSize for $ty {
+                fn size(&self) -> usize {
+                    core::mem::size_of::<Self>()
+                }
+            }
+        )*
+    };
+}
+
+impl_value_size_primitive!(
+    bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+impl ValueSize for String {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
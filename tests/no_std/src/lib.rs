@@ -0,0 +1,47 @@
+//! A build check for using the registry manager without the standard library.
+//!
+//! Run with `cargo build --manifest-path tests/no_std/Cargo.toml`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use core::hash::{BuildHasherDefault, Hasher};
+
+use registry_ticket_manager::*;
+use registry_ticket_manager_proc_macro::RegistryTicket;
+
+/// A minimal FNV-1a hasher, since `core` doesn't provide one
+#[derive(Debug, Clone, Copy)]
+pub struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, RegistryTicket)]
+pub struct Ticket(u16);
+
+pub type Registry = RegistryManager<u32, Ticket, String, BuildHasherDefault<Fnv>>;
+
+pub fn build() -> Option<(Registry, Ticket)> {
+    let mut man = Registry::default();
+    let (ticket, _old) = man.insert(String::from("cat"), 1)?;
+    *man.get_ticket_mut(ticket) += 1;
+    Some((man, ticket))
+}